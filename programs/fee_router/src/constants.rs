@@ -6,6 +6,17 @@ pub const INVESTOR_FEE_POS_OWNER_SEED: &[u8] = b"investor_fee_pos_owner";
 pub const POLICY_SEED: &[u8] = b"policy";
 pub const PROGRESS_SEED: &[u8] = b"progress";
 pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const INVESTOR_REGISTRY_SEED: &[u8] = b"investor_registry";
+pub const PAID_BITMAP_SHARD_SEED: &[u8] = b"paid_bitmap";
+pub const DISTRIBUTION_EPOCH_SEED: &[u8] = b"distribution_epoch";
+pub const REWARD_PROGRESS_SEED: &[u8] = b"reward_progress";
+pub const PENDING_PAYOUT_SEED: &[u8] = b"pending_payout";
+pub const PENDING_REWARD_PAYOUT_SEED: &[u8] = b"pending_reward_payout";
+pub const RENT_RESERVE_SEED: &[u8] = b"rent_reserve";
+pub const HONORARY_POSITION_SEED: &[u8] = b"honorary_position";
+pub const INVESTOR_SCHEDULE_SEED: &[u8] = b"investor_schedule";
+pub const INVESTOR_LEDGER_SEED: &[u8] = b"investor_ledger";
+pub const REGISTERED_LOCK_SEED: &[u8] = b"registered_lock";
 
 // Time constants
 pub const SECONDS_PER_DAY: i64 = 86400;
@@ -18,6 +29,15 @@ pub const PRECISION_MULTIPLIER: u64 = 1_000_000; // For precise calculations
 pub const MAX_PAGE_SIZE: u32 = 50; // Prevent excessive compute usage
 pub const MIN_PAYOUT_THRESHOLD: u64 = 1000; // Minimum lamports to distribute
 pub const MAX_DAILY_CAP: u64 = u64::MAX; // No cap by default
+pub const INVESTORS_PER_REGISTRY_PAGE: u32 = 64; // Investor slots per registry page account
+pub const INVESTORS_PER_BITMAP_SHARD: u32 = 8192; // Must be a multiple of INVESTORS_PER_REGISTRY_PAGE
+pub const MAX_POLICY_SIGNERS: usize = 10; // Maximum size of the policy's M-of-N admin signer set
+pub const MAX_REMAINDER_RECIPIENTS: usize = 5; // Creator remainder split targets per policy
+pub const MAX_SHARE_CURVE_POINTS: usize = 8; // Points of the time-varying investor share curve
+pub const MAX_EPOCH_LEAVES: u32 = 65536; // Keeps the claimed bitmap within the account creation limit
+pub const MIN_CLAIM_WINDOW_SECONDS: i64 = SECONDS_PER_DAY; // Investors get at least a day to claim
+pub const NUM_POOL_REWARDS: usize = 2; // cp-amm farms per pool
+pub const MAX_ADDITIONAL_POSITIONS: u8 = 4; // Extra honorary positions per vault, claimed on the first crank page
 
 // Error codes for debugging
 pub const ERR_QUOTE_VALIDATION_FAILED: u32 = 6000;
@@ -172,6 +172,48 @@ pub struct Position {
     pub padding: [u128; 6],
 }
 
+/// Liquidity vesting schedule of a locked position
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Vesting {
+    pub position: Pubkey,
+    pub cliff_point: u64,
+    pub period_frequency: u64,
+    pub cliff_unlock_liquidity: u128,
+    pub liquidity_per_period: u128,
+    pub total_released_liquidity: u128,
+    pub number_of_period: u16,
+    pub padding: [u8; 14],
+    pub padding2: [u128; 4],
+}
+
+impl Vesting {
+    pub fn get_total_lock_amount(&self) -> Option<u128> {
+        self.liquidity_per_period
+            .checked_mul(self.number_of_period.into())?
+            .checked_add(self.cliff_unlock_liquidity)
+    }
+
+    pub fn get_max_unlocked_liquidity(&self, current_point: u64) -> Option<u128> {
+        if current_point < self.cliff_point {
+            return Some(0);
+        }
+
+        if self.period_frequency == 0 {
+            return Some(self.cliff_unlock_liquidity);
+        }
+
+        let period = (current_point - self.cliff_point) / self.period_frequency;
+        let period: u128 = period.min(self.number_of_period.into()).into();
+
+        self.cliff_unlock_liquidity.checked_add(period.checked_mul(self.liquidity_per_period)?)
+    }
+
+    /// Liquidity still locked at `current_point`
+    pub fn get_locked_liquidity(&self, current_point: u64) -> Option<u128> {
+        self.get_total_lock_amount()?.checked_sub(self.get_max_unlocked_liquidity(current_point)?)
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
 pub struct PositionMetrics {
     pub total_claimed_a_fee: u64,
@@ -289,6 +331,12 @@ impl anchor_lang::Owner for Position {
     }
 }
 
+impl anchor_lang::Owner for Vesting {
+    fn owner() -> Pubkey {
+        CP_AMM_PROGRAM_ID
+    }
+}
+
 impl anchor_lang::AccountSerialize for Pool {
     fn try_serialize<W: std::io::Write>(&self, writer: &mut W) -> anchor_lang::Result<()> {
         AnchorSerialize::serialize(self, writer).map_err(Into::into)
@@ -331,6 +379,24 @@ impl anchor_lang::AccountDeserialize for Position {
     }
 }
 
+impl anchor_lang::AccountDeserialize for Vesting {
+    fn try_deserialize(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        if buf.len() < 8 {
+            return Err(ErrorCode::AccountDidNotDeserialize.into());
+        }
+        // Vesting accounts are read from investor-supplied accounts, so check the type
+        if &buf[..8] != <Vesting as anchor_lang::Discriminator>::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        Self::try_deserialize_unchecked(buf)
+    }
+
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> anchor_lang::Result<Self> {
+        let mut data: &[u8] = &buf[8..];
+        Vesting::deserialize(&mut data).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
 // Add Discriminator trait implementations
 impl anchor_lang::Discriminator for Pool {
     const DISCRIMINATOR: &'static [u8] = &[241, 154, 109, 4, 17, 177, 109, 188];
@@ -339,3 +405,7 @@ impl anchor_lang::Discriminator for Pool {
 impl anchor_lang::Discriminator for Position {
     const DISCRIMINATOR: &'static [u8] = &[170, 188, 143, 228, 122, 64, 247, 208];
 }
+
+impl anchor_lang::Discriminator for Vesting {
+    const DISCRIMINATOR: &'static [u8] = &[100, 149, 66, 138, 95, 200, 128, 241];
+}
@@ -46,4 +46,142 @@ pub enum HonouraryError {
 
     #[msg("Investor already paid in this distribution day - duplicate payment prevented")]
     InvestorAlreadyPaid = 6014,
-}
\ No newline at end of file
+
+    #[msg("Signer is not the policy authority")]
+    UnauthorizedPolicyAuthority = 6015,
+
+    #[msg("Investor index is not registered in the investor registry")]
+    InvestorNotRegistered = 6016,
+
+    #[msg("Investor accounts do not match the registry entry at this index")]
+    InvestorAccountMismatch = 6017,
+
+    #[msg("Investor slot is already registered - remove it first")]
+    InvestorAlreadyRegistered = 6018,
+
+    #[msg("Operation not allowed while a distribution day is in progress")]
+    DistributionInProgress = 6019,
+
+    #[msg("Stream account is not owned by the Streamflow program")]
+    StreamOwnerMismatch = 6020,
+
+    #[msg("Stream mint does not match the vault's investor token")]
+    StreamMintMismatch = 6021,
+
+    #[msg("Stream recipient does not own the investor ATA")]
+    StreamRecipientMismatch = 6022,
+
+    #[msg("Investor ATA is not a quote mint token account")]
+    InvestorAtaMintMismatch = 6023,
+
+    #[msg("Locked-amount snapshot not sealed - run snapshot_locked for every investor first")]
+    SnapshotNotSealed = 6024,
+
+    #[msg("Locked-amount snapshot already sealed for this day")]
+    SnapshotAlreadySealed = 6025,
+
+    #[msg("Investor already included in today's locked-amount snapshot")]
+    InvestorAlreadySnapshotted = 6026,
+
+    #[msg("No policy update is staged")]
+    PolicyUpdateNotStaged = 6027,
+
+    #[msg("Policy update timelock has not elapsed")]
+    PolicyTimelockNotElapsed = 6028,
+
+    #[msg("No policy authority transfer is pending for this signer")]
+    NoPendingAuthority = 6029,
+
+    #[msg("Invalid admin signer set - check signer count, duplicates and threshold")]
+    InvalidAdminSignerSet = 6030,
+
+    #[msg("Not enough admin signers approved this action")]
+    InsufficientAdminSigners = 6031,
+
+    #[msg("Instruction is not available in the policy's distribution mode")]
+    WrongDistributionMode = 6032,
+
+    #[msg("Invalid distribution epoch - check leaf count, amount and claim window")]
+    InvalidDistributionEpoch = 6033,
+
+    #[msg("Epoch amount exceeds the unallocated Merkle claim pool")]
+    InsufficientMerklePool = 6034,
+
+    #[msg("Invalid Merkle proof")]
+    InvalidMerkleProof = 6035,
+
+    #[msg("Distribution leaf already claimed")]
+    DistributionAlreadyClaimed = 6036,
+
+    #[msg("Distribution epoch has expired")]
+    DistributionEpochExpired = 6037,
+
+    #[msg("Distribution epoch has not expired yet")]
+    DistributionEpochNotExpired = 6038,
+
+    #[msg("Distribution epoch already swept")]
+    DistributionEpochSwept = 6039,
+
+    #[msg("Invalid remainder recipients - wallets must be distinct and weights must sum to 10000 bps")]
+    InvalidRemainderRecipients = 6040,
+
+    #[msg("Remainder recipient token account does not match the policy")]
+    RemainderRecipientMismatch = 6041,
+
+    #[msg("Liquidity deposit exceeds the funder's amount threshold")]
+    LiquidityThresholdExceeded = 6042,
+
+    #[msg("No position operation is staged")]
+    PositionOperationNotStaged = 6043,
+
+    #[msg("Position operation does not match the staged operation")]
+    PositionOperationMismatch = 6044,
+
+    #[msg("Honorary position still holds liquidity, fees or rewards")]
+    PositionNotEmpty = 6045,
+
+    #[msg("Pool reward index is not an initialized farm")]
+    InvalidRewardIndex = 6046,
+
+    #[msg("Pending payout account does not match the escrowed investor")]
+    PendingPayoutMismatch = 6047,
+
+    #[msg("No payout is pending for this investor")]
+    NoPendingPayout = 6048,
+
+    #[msg("Payout destination is not an open token account owned by the investor")]
+    InvalidPayoutDestination = 6049,
+
+    #[msg("Vault is paused")]
+    VaultPaused = 6050,
+
+    #[msg("Vault is not paused")]
+    VaultNotPaused = 6051,
+
+    #[msg("Vault already has the maximum number of honorary positions")]
+    TooManyHonoraryPositions = 6052,
+
+    #[msg("Honorary position accounts do not match the vault's registered position")]
+    HonoraryPositionMismatch = 6053,
+
+    #[msg("Lock account is not an account of the policy's locked amount source")]
+    InvalidLockAccount = 6054,
+
+    #[msg("Investor schedule cliff amount exceeds its total or it ends before its cliff")]
+    InvalidInvestorSchedule = 6055,
+
+    #[msg("Invalid investor share curve - timestamps must not decrease and shares must not exceed 10000 bps")]
+    InvalidShareCurve = 6056,
+
+    #[msg("Investor ledger account does not match the investor")]
+    InvestorLedgerMismatch = 6057,
+
+    #[msg("Distribution epoch is not claimable until its timelock has elapsed")]
+    DistributionEpochPending = 6058,
+
+    #[msg("No investor schedule change is staged")]
+    InvestorScheduleNotStaged = 6059,
+
+    #[msg("Lock account is already registered at another investor index")]
+    LockAlreadyRegistered = 6060,
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::{ CapOverflowMode, PositionOperation };
 
 #[event]
 pub struct HonoraryPositionInitialized {
@@ -19,6 +20,17 @@ pub struct PolicySetup {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct LockedSnapshotPage {
+    pub vault: Pubkey,
+    pub page_start: u32,
+    pub investors_snapshotted: u32,
+    pub page_locked: u64,
+    pub total_locked: u64,
+    pub sealed: bool,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct QuoteFeesClaimed {
     pub vault: Pubkey,
@@ -26,6 +38,83 @@ pub struct QuoteFeesClaimed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct BaseFeesClaimed {
+    pub vault: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PositionCompounded {
+    pub vault: Pubkey,
+    pub liquidity_delta: u128,
+    pub quote_amount: u64,
+    pub base_amount: u64,
+    pub quote_reserve: u64,
+    pub base_reserve: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub vault: Pubkey,
+    pub reward_index: u8,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub skipped_frozen_vault: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardPayoutPage {
+    pub vault: Pubkey,
+    pub reward_index: u8,
+    pub page_start: u32,
+    pub page_size: u32,
+    pub investors_paid: u32,
+    pub total_paid: u64,
+    pub dust_carried: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardPayoutEscrowed {
+    pub vault: Pubkey,
+    pub reward_index: u8,
+    pub investor: Pubkey,
+    pub investor_index: u32,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PendingRewardPayoutClaimed {
+    pub vault: Pubkey,
+    pub reward_index: u8,
+    pub investor: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardPayoutDayClosed {
+    pub vault: Pubkey,
+    pub reward_index: u8,
+    pub creator_amount: u64,
+    pub total_distributed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BaseFeesSwapped {
+    pub vault: Pubkey,
+    pub base_amount: u64,
+    pub quote_amount: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct InvestorPayoutPage {
     pub vault: Pubkey,
@@ -34,6 +123,10 @@ pub struct InvestorPayoutPage {
     pub investors_paid: u32,
     pub total_paid: u64,
     pub dust_carried: u64,
+    pub base_total_paid: u64,
+    pub base_dust_carried: u64,
+    pub cranker: Pubkey,
+    pub cranker_reward: u64,
     pub timestamp: i64,
 }
 
@@ -42,5 +135,267 @@ pub struct CreatorPayoutDayClosed {
     pub vault: Pubkey,
     pub creator_amount: u64,
     pub total_distributed: u64,
+    pub base_creator_amount: u64,
+    pub base_total_distributed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorRegistered {
+    pub vault: Pubkey,
+    pub investor_index: u32,
+    pub stream: Pubkey,
+    pub recipient: Pubkey,
+    pub payout_ata: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorRemoved {
+    pub vault: Pubkey,
+    pub investor_index: u32,
+    pub stream: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PolicyUpdateStaged {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PolicyUpdateApplied {
+    pub vault: Pubkey,
+    pub creator_wallet: Pubkey,
+    pub investor_fee_share_bps: u16,
+    pub daily_cap_lamports: Option<u64>,
+    pub min_payout_lamports: u64,
+    pub total_investors: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PolicyUpdateCancelled {
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PositionOperationStaged {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub operation: PositionOperation,
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PositionOperationCancelled {
+    pub vault: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HonoraryLiquidityRemoved {
+    pub vault: Pubkey,
+    pub liquidity_delta: u128,
+    pub quote_amount: u64,
+    pub base_amount: u64,
+    pub quote_destination: Pubkey,
+    pub base_destination: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HonoraryPositionMigrated {
+    pub vault: Pubkey,
+    pub old_pool: Pubkey,
+    pub old_position: Pubkey,
+    pub new_pool: Pubkey,
+    pub new_position: Pubkey,
+    pub new_position_mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PolicyAuthorityProposed {
+    pub vault: Pubkey,
+    pub current_authority: Pubkey,
+    pub proposed_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PolicyAuthorityTransferred {
+    pub vault: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PolicyAdminSignersUpdated {
+    pub vault: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MerklePoolReserved {
+    pub vault: Pubkey,
+    pub day: u64,
+    pub amount: u64,
+    pub unallocated_total: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributionEpochCommitted {
+    pub vault: Pubkey,
+    pub epoch_index: u64,
+    pub merkle_root: [u8; 32],
+    pub leaf_count: u32,
+    pub total_amount: u64,
+    pub snapshot_day: u64,
+    pub snapshot_total_locked: u64,
+    pub claimable_at: i64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributionClaimed {
+    pub vault: Pubkey,
+    pub epoch_index: u64,
+    pub leaf_index: u32,
+    pub investor: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributionEpochSwept {
+    pub vault: Pubkey,
+    pub epoch_index: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RemainderRecipientPaid {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub token_account: Pubkey,
+    pub bps: u16,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+#[event]
+pub struct PayoutEscrowed {
+    pub vault: Pubkey,
+    pub investor: Pubkey,
+    pub investor_index: u32,
+    pub quote_amount: u64,
+    pub base_amount: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct InvestorPaid {
+    pub vault: Pubkey,
+    pub investor: Pubkey,
+    pub investor_index: u32,
+    pub day: u64,
+    pub locked_amount: u64,
+    pub quote_amount: u64,
+    pub base_amount: u64,
+    pub quote_escrowed: u64,
+    pub base_escrowed: u64,
+    pub total_quote_paid: u64,
+    pub total_base_paid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PendingPayoutClaimed {
+    pub vault: Pubkey,
+    pub investor: Pubkey,
+    pub quote_amount: u64,
+    pub base_amount: u64,
+    pub quote_destination: Pubkey,
+    pub base_destination: Option<Pubkey>,
+    pub ata_created: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapOverflowSettled {
+    pub vault: Pubkey,
+    pub mode: CapOverflowMode,
+    pub quote_amount: u64,
+    pub base_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultPaused {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultUnpaused {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub paused_seconds: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HonoraryPositionAdded {
+    pub vault: Pubkey,
+    pub position_index: u8,
+    pub pool: Pubkey,
+    pub position: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HonoraryPositionFeesClaimed {
+    pub vault: Pubkey,
+    pub position_index: u8,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorScheduleSet {
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub schedule: Pubkey,
+    pub total_amount: u64,
+    pub cliff_ts: i64,
+    pub cliff_amount: u64,
+    pub end_ts: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InvestorScheduleStaged {
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub schedule: Pubkey,
+    pub total_amount: u64,
+    pub cliff_ts: i64,
+    pub cliff_amount: u64,
+    pub end_ts: i64,
+    pub eta: i64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::Policy,
+    events::PolicyAuthorityTransferred,
+};
+
+#[derive(Accounts)]
+pub struct AcceptPolicyAuthority<'info> {
+    /// Proposed authority accepting the transfer
+    pub new_authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+}
+
+pub fn handle_accept_policy_authority(ctx: Context<AcceptPolicyAuthority>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let policy = &mut ctx.accounts.policy;
+
+    require!(
+        policy.pending_authority == Some(ctx.accounts.new_authority.key()),
+        HonouraryError::NoPendingAuthority
+    );
+
+    let previous_authority = policy.authority;
+    policy.authority = ctx.accounts.new_authority.key();
+    policy.pending_authority = None;
+    policy.updated_at = current_time;
+
+    emit!(PolicyAuthorityTransferred {
+        vault: ctx.accounts.vault.key(),
+        previous_authority,
+        new_authority: policy.authority,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
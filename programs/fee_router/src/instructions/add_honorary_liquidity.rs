@@ -1,5 +1,4 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::{ instruction::AccountMeta, program::invoke_signed };
 use anchor_spl::token_interface::{
     TokenAccount,
     TokenInterface,
@@ -10,8 +9,13 @@ use anchor_spl::token_interface::{
 use crate::cp_amm_types::{ Pool, Position };
 use crate::{
     constants::*,
-    state::InvestorFeePositionOwner,
-    utils::pda::position_owner_signer_seeds,
+    state::{ InvestorFeePositionOwner, Policy },
+    utils::{
+        math::amounts_for_liquidity,
+        pda::position_owner_signer_seeds,
+        token::transfer_fee_included_amount,
+    },
+    integrations::cp_amm::{ add_liquidity, AddLiquidityAccounts },
     error::HonouraryError,
 };
 
@@ -32,6 +36,10 @@ pub struct AddHonoraryLiquidity<'info> {
     )]
     pub position_owner: Box<Account<'info, InvestorFeePositionOwner>>,
 
+    /// Distribution policy (pause state)
+    #[account(seeds = [POLICY_SEED, vault.key().as_ref()], bump = policy.bump)]
+    pub policy: Box<Account<'info, Policy>>,
+
     /// Honorary position
     #[account(
         mut,
@@ -52,6 +60,11 @@ pub struct AddHonoraryLiquidity<'info> {
     pub quote_mint: InterfaceAccount<'info, Mint>,
 
     /// Base mint
+    #[account(
+        constraint = base_mint.key() != quote_mint.key() @ HonouraryError::InvalidPoolConfiguration,
+        constraint = (base_mint.key() == pool.token_a_mint || base_mint.key() == pool.token_b_mint)
+            @ HonouraryError::InvalidPoolConfiguration
+    )]
     pub base_mint: InterfaceAccount<'info, Mint>,
 
     /// Quote vault from pool
@@ -100,6 +113,12 @@ pub struct AddHonoraryLiquidity<'info> {
     pub event_authority: UncheckedAccount<'info>,
 }
 
+/// Add liquidity to the honorary position on behalf of a funder.
+///
+/// The thresholds are the most of token A / token B (in pool order) the funder is
+/// willing to pay, transfer fees included. Only the exact amounts cp-amm needs for
+/// `liquidity_delta` at the current price are pulled into the treasury, and any
+/// residue is refunded, so the treasury balance keeps reflecting fees only.
 pub fn handle_add_honorary_liquidity(
     ctx: Context<AddHonoraryLiquidity>,
     liquidity_delta: u128,
@@ -108,51 +127,88 @@ pub fn handle_add_honorary_liquidity(
 ) -> Result<()> {
     msg!("Adding liquidity to honorary position");
 
+    ctx.accounts.policy.require_not_paused()?;
+
     require!(liquidity_delta > 0, HonouraryError::MathOverflow);
 
-    // Step 1: Transfer tokens from funder to PDA-owned treasury accounts
-    // This is necessary because CP-AMM's add_liquidity requires the owner (PDA in our case)
-    // to have authority over the token accounts being deposited
+    // Determine account ordering based on pool's token layout
+    let quote_is_token_a = ctx.accounts.pool.token_a_mint == ctx.accounts.quote_mint.key();
 
-    // Transfer quote tokens
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.quote_token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.funder_quote_account.to_account_info(),
-                mint: ctx.accounts.quote_mint.to_account_info(),
-                to: ctx.accounts.quote_treasury.to_account_info(),
-                authority: ctx.accounts.funder.to_account_info(),
-            }
-        ),
-        token_a_amount_threshold, // Use threshold as max amount to transfer
-        ctx.accounts.quote_mint.decimals
+    // Step 1: Exact amounts cp-amm takes for this liquidity at the current price
+    let pool = &ctx.accounts.pool;
+    let (amount_a, amount_b) = amounts_for_liquidity(
+        liquidity_delta,
+        pool.sqrt_price,
+        pool.sqrt_min_price,
+        pool.sqrt_max_price
     )?;
+    let (quote_amount, base_amount) = if quote_is_token_a {
+        (amount_a, amount_b)
+    } else {
+        (amount_b, amount_a)
+    };
 
-    // Transfer base tokens
-    transfer_checked(
-        CpiContext::new(
-            ctx.accounts.base_token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.funder_base_account.to_account_info(),
-                mint: ctx.accounts.base_mint.to_account_info(),
-                to: ctx.accounts.base_treasury.to_account_info(),
-                authority: ctx.accounts.funder.to_account_info(),
-            }
-        ),
-        token_b_amount_threshold, // Use threshold as max amount to transfer
-        ctx.accounts.base_mint.decimals
-    )?;
+    // cp-amm pulls the transfer-fee-inclusive amount out of the treasury, and the
+    // funder's transfer into the treasury is charged the fee again
+    let quote_required = transfer_fee_included_amount(&ctx.accounts.quote_mint, quote_amount)?;
+    let base_required = transfer_fee_included_amount(&ctx.accounts.base_mint, base_amount)?;
+    let quote_deposit = transfer_fee_included_amount(&ctx.accounts.quote_mint, quote_required)?;
+    let base_deposit = transfer_fee_included_amount(&ctx.accounts.base_mint, base_required)?;
 
-    // Step 2: Prepare signer seeds for PDA
+    let (quote_threshold, base_threshold) = if quote_is_token_a {
+        (token_a_amount_threshold, token_b_amount_threshold)
+    } else {
+        (token_b_amount_threshold, token_a_amount_threshold)
+    };
+    require!(
+        quote_deposit <= quote_threshold && base_deposit <= base_threshold,
+        HonouraryError::LiquidityThresholdExceeded
+    );
+
+    let quote_treasury_before = ctx.accounts.quote_treasury.amount;
+    let base_treasury_before = ctx.accounts.base_treasury.amount;
+
+    // Step 2: Transfer tokens from funder to PDA-owned treasury accounts
+    // This is necessary because CP-AMM's add_liquidity requires the owner (PDA in our case)
+    // to have authority over the token accounts being deposited
+    if quote_deposit > 0 {
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.quote_token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.funder_quote_account.to_account_info(),
+                    mint: ctx.accounts.quote_mint.to_account_info(),
+                    to: ctx.accounts.quote_treasury.to_account_info(),
+                    authority: ctx.accounts.funder.to_account_info(),
+                }
+            ),
+            quote_deposit,
+            ctx.accounts.quote_mint.decimals
+        )?;
+    }
+
+    if base_deposit > 0 {
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.base_token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.funder_base_account.to_account_info(),
+                    mint: ctx.accounts.base_mint.to_account_info(),
+                    to: ctx.accounts.base_treasury.to_account_info(),
+                    authority: ctx.accounts.funder.to_account_info(),
+                }
+            ),
+            base_deposit,
+            ctx.accounts.base_mint.decimals
+        )?;
+    }
+
+    // Step 3: Prepare signer seeds for PDA
     let vault_key = ctx.accounts.vault.key();
     let bump_slice = [ctx.accounts.position_owner.bump];
     let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
     let signer_seeds_ref = &[&signer_seeds[..]];
 
-    // Determine account ordering based on pool's token layout
-    let quote_is_token_a = ctx.accounts.pool.token_a_mint == ctx.accounts.quote_mint.key();
-
     // Use treasury accounts (PDA-owned) for add_liquidity
     let (token_a_account, token_b_account) = if quote_is_token_a {
         (&ctx.accounts.quote_treasury, &ctx.accounts.base_treasury)
@@ -178,59 +234,86 @@ pub fn handle_add_honorary_liquidity(
         (&ctx.accounts.base_token_program, &ctx.accounts.quote_token_program)
     };
 
-    // Step 3: Build CP-AMM add_liquidity instruction data
-    // Discriminator from CP-AMM IDL
-    let mut instruction_data = vec![181, 157, 89, 67, 143, 182, 52, 72];
-
-    // Serialize AddLiquidityParameters struct
-    instruction_data.extend_from_slice(&liquidity_delta.to_le_bytes());
-    instruction_data.extend_from_slice(&token_a_amount_threshold.to_le_bytes());
-    instruction_data.extend_from_slice(&token_b_amount_threshold.to_le_bytes());
+    let (token_a_required, token_b_required) = if quote_is_token_a {
+        (quote_required, base_required)
+    } else {
+        (base_required, quote_required)
+    };
 
     // Step 4: Call CP-AMM add_liquidity via CPI with PDA as the owner
     // The PDA owns both the position NFT and the treasury token accounts,
     // so it can sign for adding liquidity from the treasury accounts
-    invoke_signed(
-        &(anchor_lang::solana_program::instruction::Instruction {
-            program_id: ctx.accounts.cp_amm_program.key(),
-            accounts: vec![
-                AccountMeta::new(ctx.accounts.pool.key(), false),
-                AccountMeta::new(ctx.accounts.position.key(), false),
-                AccountMeta::new(token_a_account.key(), false), // Treasury account (PDA-owned)
-                AccountMeta::new(token_b_account.key(), false), // Treasury account (PDA-owned)
-                AccountMeta::new(token_a_vault.key(), false),
-                AccountMeta::new(token_b_vault.key(), false),
-                AccountMeta::new_readonly(token_a_mint.key(), false),
-                AccountMeta::new_readonly(token_b_mint.key(), false),
-                AccountMeta::new_readonly(ctx.accounts.position_nft_account.key(), false),
-                AccountMeta::new_readonly(ctx.accounts.position_owner.key(), true), // PDA signs (owns NFT & treasury)
-                AccountMeta::new_readonly(token_a_program.key(), false),
-                AccountMeta::new_readonly(token_b_program.key(), false),
-                AccountMeta::new_readonly(ctx.accounts.event_authority.key(), false), // CP-AMM event authority
-                AccountMeta::new_readonly(ctx.accounts.cp_amm_program.key(), false), // CP-AMM program
-            ],
-            data: instruction_data,
-        }),
-        &[
-            ctx.accounts.pool.to_account_info(),
-            ctx.accounts.position.to_account_info(),
-            token_a_account.to_account_info(),
-            token_b_account.to_account_info(),
-            token_a_vault.to_account_info(),
-            token_b_vault.to_account_info(),
-            token_a_mint.to_account_info(),
-            token_b_mint.to_account_info(),
-            ctx.accounts.position_nft_account.to_account_info(),
-            ctx.accounts.position_owner.to_account_info(),
-            token_a_program.to_account_info(),
-            token_b_program.to_account_info(),
-            ctx.accounts.event_authority.to_account_info(), // CP-AMM event authority
-            ctx.accounts.cp_amm_program.to_account_info(), // CP-AMM program
-        ],
+    add_liquidity(
+        &AddLiquidityAccounts {
+            pool: &ctx.accounts.pool.to_account_info(),
+            position: &ctx.accounts.position.to_account_info(),
+            position_nft_account: &ctx.accounts.position_nft_account.to_account_info(),
+            position_owner_pda: &ctx.accounts.position_owner.to_account_info(),
+            token_a_account: &token_a_account.to_account_info(), // Treasury account (PDA-owned)
+            token_b_account: &token_b_account.to_account_info(), // Treasury account (PDA-owned)
+            token_a_vault: &token_a_vault.to_account_info(),
+            token_b_vault: &token_b_vault.to_account_info(),
+            token_a_mint: &token_a_mint.to_account_info(),
+            token_b_mint: &token_b_mint.to_account_info(),
+            token_a_program: &token_a_program.to_account_info(),
+            token_b_program: &token_b_program.to_account_info(),
+            event_authority: &ctx.accounts.event_authority.to_account_info(),
+            cp_amm_program: &ctx.accounts.cp_amm_program.to_account_info(),
+        },
+        liquidity_delta,
+        token_a_required,
+        token_b_required,
         signer_seeds_ref
     )?;
 
-    msg!("Successfully added {} liquidity to honorary position", liquidity_delta);
+    // Step 5: Refund whatever the deposit left behind in the treasuries
+    ctx.accounts.quote_treasury.reload()?;
+    ctx.accounts.base_treasury.reload()?;
+    let quote_residue = ctx.accounts.quote_treasury.amount.saturating_sub(quote_treasury_before);
+    let base_residue = ctx.accounts.base_treasury.amount.saturating_sub(base_treasury_before);
+
+    if quote_residue > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.quote_token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.quote_treasury.to_account_info(),
+                    mint: ctx.accounts.quote_mint.to_account_info(),
+                    to: ctx.accounts.funder_quote_account.to_account_info(),
+                    authority: ctx.accounts.position_owner.to_account_info(),
+                },
+                signer_seeds_ref
+            ),
+            quote_residue,
+            ctx.accounts.quote_mint.decimals
+        )?;
+    }
+
+    if base_residue > 0 {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.base_token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.base_treasury.to_account_info(),
+                    mint: ctx.accounts.base_mint.to_account_info(),
+                    to: ctx.accounts.funder_base_account.to_account_info(),
+                    authority: ctx.accounts.position_owner.to_account_info(),
+                },
+                signer_seeds_ref
+            ),
+            base_residue,
+            ctx.accounts.base_mint.decimals
+        )?;
+    }
+
+    msg!(
+        "Successfully added {} liquidity to honorary position (quote {}, base {}, refunded {}/{})",
+        liquidity_delta,
+        quote_deposit,
+        base_deposit,
+        quote_residue,
+        base_residue
+    );
 
     Ok(())
 }
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ Mint, TokenInterface, TokenAccount };
+use crate::cp_amm_types::Pool;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ InvestorFeePositionOwner, HonoraryPosition, FeeCollection, Policy },
+    utils::{ validation::preflight_position_validation, pda::position_owner_signer_seeds },
+    integrations::cp_amm::{ create_honorary_position, CreateHonoraryPositionAccounts },
+    events::HonoraryPositionAdded,
+};
+
+#[derive(Accounts)]
+pub struct AddHonoraryPosition<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Payer for account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Position owner PDA (owns every honorary position of the vault)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = position_owner.bump
+    )]
+    pub position_owner: Box<Account<'info, InvestorFeePositionOwner>>,
+
+    /// Distribution policy
+    #[account(seeds = [POLICY_SEED, vault.key().as_ref()], bump = policy.bump)]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// Record of the new position
+    #[account(
+        init,
+        seeds = [
+            HONORARY_POSITION_SEED,
+            vault.key().as_ref(),
+            &[position_owner.additional_position_count + 1]
+        ],
+        bump,
+        payer = payer,
+        space = 8 + HonoraryPosition::INIT_SPACE
+    )]
+    pub honorary_position: Box<Account<'info, HonoraryPosition>>,
+
+    /// DAMM v2 pool to open the position in
+    #[account(
+        mut,
+        constraint = pool.key() != position_owner.pool @ HonouraryError::InvalidPoolConfiguration
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Quote mint shared by all of the vault's positions
+    #[account(constraint = quote_mint.key() == position_owner.quote_mint)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The pool's other mint
+    #[account(
+        constraint = base_mint.key() != quote_mint.key() @ HonouraryError::InvalidPoolConfiguration,
+        constraint = (base_mint.key() == pool.token_a_mint || base_mint.key() == pool.token_b_mint)
+            @ HonouraryError::InvalidPoolConfiguration
+    )]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Position NFT mint (will be created by CP-AMM CPI)
+    /// CHECK: Must be a signer keypair, will be initialized by CP-AMM
+    #[account(mut, signer)]
+    pub position_nft_mint: UncheckedAccount<'info>,
+
+    /// Position NFT token account (will be created by CP-AMM CPI)
+    /// CHECK: Will be initialized by CP-AMM as a PDA
+    #[account(mut)]
+    pub position_nft_account: UncheckedAccount<'info>,
+
+    /// Position account (will be created by CP-AMM)
+    /// CHECK: Created by CP-AMM CPI
+    #[account(mut)]
+    pub position: UncheckedAccount<'info>,
+
+    /// Pool authority from CP-AMM
+    /// CHECK: CP-AMM pool authority PDA
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// Event authority for CP-AMM CPI events
+    /// CHECK: PDA for event authority, derived by CP-AMM program
+    pub event_authority: UncheckedAccount<'info>,
+
+    /// Program account for CP-AMM (needed for event_authority derivation)
+    /// CHECK: This is the CP-AMM program account
+    pub cp_amm_program_account: UncheckedAccount<'info>,
+
+    /// Treasury for the pool's other mint, the claim CPI's token account for it
+    /// (stays empty: additional positions are quote-only)
+    #[account(
+        init_if_needed,
+        seeds = [TREASURY_SEED, vault.key().as_ref(), base_mint.key().as_ref()],
+        bump,
+        payer = payer,
+        token::mint = base_mint,
+        token::authority = position_owner,
+        token::token_program = token_program
+    )]
+    pub base_treasury_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Program accounts
+    pub cp_amm_program: Program<'info, crate::cp_amm_types::CpAmm>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token-2022 program for CP-AMM CPI (CP-AMM requires Token-2022)
+    /// CHECK: Token-2022 program
+    pub token_2022_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Open another honorary position for the vault in a pool with the same quote
+/// mint. Its fees are claimed on the first crank page of each day and added to
+/// the day's quote pool.
+pub fn handle_add_honorary_position(ctx: Context<AddHonoraryPosition>) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    let position_owner = &ctx.accounts.position_owner;
+    require!(
+        position_owner.additional_position_count < MAX_ADDITIONAL_POSITIONS,
+        HonouraryError::TooManyHonoraryPositions
+    );
+
+    // Base fees from another pool could not be paid out in the vault's base token
+    preflight_position_validation(&ctx.accounts.pool, &position_owner.quote_mint, FeeCollection::QuoteOnly)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let index = position_owner.additional_position_count + 1;
+
+    let honorary_position = &mut ctx.accounts.honorary_position;
+    honorary_position.vault = ctx.accounts.vault.key();
+    honorary_position.index = index;
+    honorary_position.bump = ctx.bumps.honorary_position;
+    honorary_position.pool = ctx.accounts.pool.key();
+    honorary_position.position_mint = ctx.accounts.position_nft_mint.key();
+    honorary_position.position_account = ctx.accounts.position.key();
+    honorary_position.base_mint = ctx.accounts.base_mint.key();
+    honorary_position.created_at = current_time;
+    honorary_position.total_fees_claimed = 0;
+    honorary_position.last_claimed_day = 0;
+
+    let vault_key = ctx.accounts.vault.key();
+    let bump_slice = [ctx.accounts.position_owner.bump];
+    let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+
+    create_honorary_position(
+        &CreateHonoraryPositionAccounts {
+            pool: &ctx.accounts.pool,
+            position_owner_pda: &ctx.accounts.position_owner.to_account_info(),
+            position_nft_mint: &ctx.accounts.position_nft_mint.to_account_info(),
+            position: &ctx.accounts.position,
+            position_nft_account: &ctx.accounts.position_nft_account.to_account_info(),
+            pool_authority: &ctx.accounts.pool_authority,
+            event_authority: &ctx.accounts.event_authority,
+            cp_amm_program_account: &ctx.accounts.cp_amm_program_account,
+            cp_amm_program: &ctx.accounts.cp_amm_program.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            token_program: &ctx.accounts.token_2022_program.to_account_info(),
+            payer: &ctx.accounts.payer.to_account_info(),
+        },
+        &[&signer_seeds[..]]
+    )?;
+
+    ctx.accounts.position_owner.additional_position_count = index;
+
+    emit!(HonoraryPositionAdded {
+        vault: vault_key,
+        position_index: index,
+        pool: ctx.accounts.pool.key(),
+        position: ctx.accounts.position.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ DistributionProgress, InvestorSchedule },
+    events::InvestorScheduleSet,
+};
+
+#[derive(Accounts)]
+pub struct ApplyInvestorSchedule<'info> {
+    /// Anyone can apply staged terms once their timelock has elapsed (permissionless)
+    pub payer: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution progress (schedules may only change between days)
+    #[account(seeds = [PROGRESS_SEED, vault.key().as_ref()], bump = progress.bump)]
+    pub progress: Box<Account<'info, DistributionProgress>>,
+
+    /// The investor's schedule
+    #[account(
+        mut,
+        seeds = [
+            INVESTOR_SCHEDULE_SEED,
+            vault.key().as_ref(),
+            investor_schedule.recipient.as_ref()
+        ],
+        bump = investor_schedule.bump
+    )]
+    pub investor_schedule: Box<Account<'info, InvestorSchedule>>,
+}
+
+pub fn handle_apply_investor_schedule(ctx: Context<ApplyInvestorSchedule>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Never change a schedule while a day's snapshot/payouts are in flight
+    require!(ctx.accounts.progress.day_completed, HonouraryError::DistributionInProgress);
+
+    let schedule = &mut ctx.accounts.investor_schedule;
+    let terms = schedule.apply_staged_terms(current_time)?;
+
+    emit!(InvestorScheduleSet {
+        vault: ctx.accounts.vault.key(),
+        recipient: schedule.recipient,
+        schedule: schedule.key(),
+        total_amount: terms.total_amount,
+        cliff_ts: terms.cliff_ts,
+        cliff_amount: terms.cliff_amount,
+        end_ts: terms.end_ts,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ Policy, DistributionProgress },
+    events::PolicyUpdateApplied,
+};
+
+#[derive(Accounts)]
+pub struct ApplyPolicyUpdate<'info> {
+    /// Anyone can apply an update once its timelock has elapsed (permissionless)
+    pub payer: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// Distribution progress (updates only land between days)
+    #[account(seeds = [PROGRESS_SEED, vault.key().as_ref()], bump = progress.bump)]
+    pub progress: Box<Account<'info, DistributionProgress>>,
+}
+
+pub fn handle_apply_policy_update(ctx: Context<ApplyPolicyUpdate>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let policy = &mut ctx.accounts.policy;
+
+    // Never change parameters while a day's snapshot/payouts are in flight
+    require!(ctx.accounts.progress.day_completed, HonouraryError::DistributionInProgress);
+    require!(current_time >= policy.pending_update_eta, HonouraryError::PolicyTimelockNotElapsed);
+
+    let update = policy.pending_update.take().ok_or(HonouraryError::PolicyUpdateNotStaged)?;
+    policy.apply_update(&update);
+    policy.pending_update_eta = 0;
+    policy.updated_at = current_time;
+
+    emit!(PolicyUpdateApplied {
+        vault: ctx.accounts.vault.key(),
+        creator_wallet: policy.creator_wallet,
+        investor_fee_share_bps: policy.investor_fee_share_bps,
+        daily_cap_lamports: policy.daily_cap_lamports,
+        min_payout_lamports: policy.min_payout_lamports,
+        total_investors: policy.total_investors,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::Policy,
+    events::PolicyUpdateCancelled,
+};
+
+#[derive(Accounts)]
+pub struct CancelPolicyUpdate<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+}
+
+pub fn handle_cancel_policy_update(ctx: Context<CancelPolicyUpdate>) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    let policy = &mut ctx.accounts.policy;
+
+    require!(policy.pending_update.is_some(), HonouraryError::PolicyUpdateNotStaged);
+    policy.pending_update = None;
+    policy.pending_update_eta = 0;
+
+    emit!(PolicyUpdateCancelled {
+        vault: ctx.accounts.vault.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::Policy,
+    events::PositionOperationCancelled,
+};
+
+#[derive(Accounts)]
+pub struct CancelPositionOperation<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+}
+
+pub fn handle_cancel_position_operation(ctx: Context<CancelPositionOperation>) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    let policy = &mut ctx.accounts.policy;
+
+    require!(
+        policy.pending_position_operation.is_some(),
+        HonouraryError::PositionOperationNotStaged
+    );
+    policy.pending_position_operation = None;
+    policy.pending_position_operation_eta = 0;
+
+    emit!(PositionOperationCancelled {
+        vault: ctx.accounts.vault.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint,
+    TokenAccount,
+    TokenInterface,
+    transfer_checked,
+    TransferChecked,
+};
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ InvestorFeePositionOwner, DistributionProgress, DistributionEpoch },
+    utils::{ merkle::{ distribution_leaf, verify_proof }, pda::position_owner_signer_seeds },
+    events::DistributionClaimed,
+};
+
+#[derive(Accounts)]
+pub struct ClaimDistribution<'info> {
+    /// Anyone can submit a claim on the investor's behalf
+    pub claimer: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Position owner PDA (treasury authority)
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            INVESTOR_FEE_POS_OWNER_SEED
+        ],
+        bump = position_owner.bump
+    )]
+    pub position_owner: Box<Account<'info, InvestorFeePositionOwner>>,
+
+    /// Quote mint
+    #[account(constraint = quote_mint.key() == position_owner.quote_mint)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program-owned treasury for quote tokens
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, vault.key().as_ref(), quote_mint.key().as_ref()],
+        bump,
+        token::mint = quote_mint,
+        token::authority = position_owner
+    )]
+    pub treasury_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Distribution progress (lifetime investor totals)
+    #[account(
+        mut,
+        seeds = [PROGRESS_SEED, vault.key().as_ref()],
+        bump = progress.bump
+    )]
+    pub progress: Box<Account<'info, DistributionProgress>>,
+
+    /// Claim epoch
+    #[account(
+        mut,
+        seeds = [
+            DISTRIBUTION_EPOCH_SEED,
+            vault.key().as_ref(),
+            &epoch.epoch_index.to_le_bytes()
+        ],
+        bump = epoch.bump
+    )]
+    pub epoch: Box<Account<'info, DistributionEpoch>>,
+
+    /// Investor wallet committed in the leaf
+    /// CHECK: Only used as the leaf key and destination token account authority
+    pub investor: UncheckedAccount<'info>,
+
+    /// Any quote token account owned by the investor
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = investor
+    )]
+    pub investor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claim one leaf of a distribution epoch. The payout can go to any quote token
+/// account the investor owns, so a closed or frozen ATA never blocks a claim.
+/// Merkle claims are not recorded in the push-mode `InvestorLedger`.
+pub fn handle_claim_distribution(
+    ctx: Context<ClaimDistribution>,
+    leaf_index: u32,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let epoch = &mut ctx.accounts.epoch;
+
+    require!(!epoch.swept, HonouraryError::DistributionEpochSwept);
+    require!(current_time >= epoch.claimable_at, HonouraryError::DistributionEpochPending);
+    require!(current_time < epoch.expires_at, HonouraryError::DistributionEpochExpired);
+    require!(leaf_index < epoch.leaf_count, HonouraryError::InvalidMerkleProof);
+    require!(!epoch.is_claimed(leaf_index), HonouraryError::DistributionAlreadyClaimed);
+
+    let leaf = distribution_leaf(leaf_index, &ctx.accounts.investor.key(), amount);
+    require!(verify_proof(&proof, &epoch.merkle_root, leaf), HonouraryError::InvalidMerkleProof);
+
+    // A bad tree must never draw on funds reserved for other epochs
+    let claimed_amount = epoch.claimed_amount
+        .checked_add(amount)
+        .ok_or(HonouraryError::MathOverflow)?;
+    require!(claimed_amount <= epoch.total_amount, HonouraryError::InsufficientMerklePool);
+
+    epoch.mark_claimed(leaf_index)?;
+    epoch.claimed_amount = claimed_amount;
+
+    if amount > 0 {
+        let vault_key = ctx.accounts.vault.key();
+        let bump_slice = [ctx.accounts.position_owner.bump];
+        let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.treasury_ata.to_account_info(),
+                    mint: ctx.accounts.quote_mint.to_account_info(),
+                    to: ctx.accounts.investor_token_account.to_account_info(),
+                    authority: ctx.accounts.position_owner.to_account_info(),
+                },
+                &[&signer_seeds[..]]
+            ),
+            amount,
+            ctx.accounts.quote_mint.decimals
+        )?;
+    }
+
+    let progress = &mut ctx.accounts.progress;
+    progress.total_investor_distributed = progress.total_investor_distributed.saturating_add(amount);
+
+    emit!(DistributionClaimed {
+        vault: ctx.accounts.vault.key(),
+        epoch_index: epoch.epoch_index,
+        leaf_index,
+        investor: ctx.accounts.investor.key(),
+        amount,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
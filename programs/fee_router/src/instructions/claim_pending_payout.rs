@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{
+    self,
+    get_associated_token_address_with_program_id,
+    AssociatedToken,
+    Create,
+};
+use anchor_spl::token_interface::{
+    Mint,
+    TokenAccount,
+    TokenInterface,
+    transfer_checked,
+    TransferChecked,
+};
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ InvestorFeePositionOwner, Policy, DistributionProgress, PendingPayout, InvestorLedger },
+    utils::pda::position_owner_signer_seeds,
+    events::PendingPayoutClaimed,
+};
+
+#[derive(Accounts)]
+pub struct ClaimPendingPayout<'info> {
+    /// Anyone can submit a claim on the investor's behalf
+    pub claimer: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Position owner PDA (treasury authority)
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            INVESTOR_FEE_POS_OWNER_SEED
+        ],
+        bump = position_owner.bump
+    )]
+    pub position_owner: Box<Account<'info, InvestorFeePositionOwner>>,
+
+    /// Distribution policy
+    #[account(seeds = [POLICY_SEED, vault.key().as_ref()], bump = policy.bump)]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// Distribution progress (outstanding escrow totals)
+    #[account(
+        mut,
+        seeds = [PROGRESS_SEED, vault.key().as_ref()],
+        bump = progress.bump
+    )]
+    pub progress: Box<Account<'info, DistributionProgress>>,
+
+    /// Investor wallet the escrow belongs to
+    /// CHECK: Only used as PDA seed and destination token account authority
+    pub investor: UncheckedAccount<'info>,
+
+    /// The investor's escrow
+    #[account(
+        mut,
+        seeds = [PENDING_PAYOUT_SEED, vault.key().as_ref(), investor.key().as_ref()],
+        bump = pending_payout.bump,
+        constraint = pending_payout.investor == investor.key() @ HonouraryError::PendingPayoutMismatch
+    )]
+    pub pending_payout: Box<Account<'info, PendingPayout>>,
+
+    /// The investor's ledger. Always passed; it is only left untouched when it was
+    /// never created (escrows made before ledgers existed).
+    /// CHECK: Address checked by seeds, deserialized in the handler when initialized
+    #[account(
+        mut,
+        seeds = [INVESTOR_LEDGER_SEED, vault.key().as_ref(), investor.key().as_ref()],
+        bump
+    )]
+    pub investor_ledger: UncheckedAccount<'info>,
+
+    /// Quote mint
+    #[account(constraint = quote_mint.key() == position_owner.quote_mint)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program-owned treasury for quote tokens
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, vault.key().as_ref(), quote_mint.key().as_ref()],
+        bump,
+        token::mint = quote_mint,
+        token::authority = position_owner
+    )]
+    pub treasury_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Quote token account owned by the investor (or their ATA to create)
+    /// CHECK: Validated in the handler, after optional ATA creation
+    #[account(mut)]
+    pub quote_destination: UncheckedAccount<'info>,
+
+    /// Base mint (required when base is owed)
+    #[account(constraint = base_mint.key() == pending_payout.base_mint @ HonouraryError::PendingPayoutMismatch)]
+    pub base_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Program-owned treasury for base tokens (required when base is owed)
+    #[account(mut)]
+    pub base_treasury_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Base token account owned by the investor (required when base is owed)
+    /// CHECK: Validated in the handler, after optional ATA creation
+    #[account(mut)]
+    pub base_destination: Option<UncheckedAccount<'info>>,
+
+    /// Lamport reserve paying for investor ATAs when the policy enables
+    /// `create_payout_atas`. Funded by plain SOL transfers to its address.
+    #[account(mut, seeds = [RENT_RESERVE_SEED, vault.key().as_ref()], bump)]
+    pub rent_reserve: Option<SystemAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Withdraw payouts the crank escrowed because the investor's payout account
+/// could not receive them. The payout can go to any token account the investor
+/// owns; with `create_payout_atas` on, a missing investor ATA is created first
+/// with the vault's rent reserve paying.
+pub fn handle_claim_pending_payout(ctx: Context<ClaimPendingPayout>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let (quote_amount, base_amount) = ctx.accounts.pending_payout.take();
+    require!(quote_amount > 0 || base_amount > 0, HonouraryError::NoPendingPayout);
+
+    let vault_key = ctx.accounts.vault.key();
+    let bump_slice = [ctx.accounts.position_owner.bump];
+    let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+    let accounts = &ctx.accounts;
+    let mut ata_created = false;
+
+    if quote_amount > 0 {
+        let destination = accounts.quote_destination.to_account_info();
+        ata_created |= prepare_destination(accounts, &destination, &accounts.quote_mint, ctx.bumps.rent_reserve)?;
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.treasury_ata.to_account_info(),
+                    mint: accounts.quote_mint.to_account_info(),
+                    to: destination,
+                    authority: accounts.position_owner.to_account_info(),
+                },
+                &[&signer_seeds[..]]
+            ),
+            quote_amount,
+            accounts.quote_mint.decimals
+        )?;
+    }
+
+    let mut base_destination_key = None;
+    if base_amount > 0 {
+        let base_mint = accounts.base_mint.as_ref().ok_or(HonouraryError::AccountCountMismatch)?;
+        let base_treasury = accounts.base_treasury_ata
+            .as_ref()
+            .ok_or(HonouraryError::AccountCountMismatch)?;
+        let (expected_treasury, _) = Pubkey::find_program_address(
+            &[TREASURY_SEED, vault_key.as_ref(), base_mint.key().as_ref()],
+            &crate::ID
+        );
+        require_keys_eq!(base_treasury.key(), expected_treasury, HonouraryError::InvalidPoolConfiguration);
+
+        let destination = accounts.base_destination
+            .as_ref()
+            .ok_or(HonouraryError::AccountCountMismatch)?
+            .to_account_info();
+        ata_created |= prepare_destination(accounts, &destination, base_mint, ctx.bumps.rent_reserve)?;
+        base_destination_key = Some(destination.key());
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: base_treasury.to_account_info(),
+                    mint: base_mint.to_account_info(),
+                    to: destination,
+                    authority: accounts.position_owner.to_account_info(),
+                },
+                &[&signer_seeds[..]]
+            ),
+            base_amount,
+            base_mint.decimals
+        )?;
+    }
+
+    let progress = &mut ctx.accounts.progress;
+    progress.pending_payout_quote = progress.pending_payout_quote.saturating_sub(quote_amount);
+    progress.pending_payout_base = progress.pending_payout_base.saturating_sub(base_amount);
+
+    let ledger_info = ctx.accounts.investor_ledger.to_account_info();
+    if ledger_info.owner == &crate::ID {
+        let mut investor_ledger = InvestorLedger::try_deserialize(&mut &ledger_info.try_borrow_data()?[..])?;
+        investor_ledger.record_claim(quote_amount, base_amount);
+        investor_ledger.try_serialize(&mut &mut ledger_info.try_borrow_mut_data()?[..])?;
+    }
+
+    emit!(PendingPayoutClaimed {
+        vault: vault_key,
+        investor: ctx.accounts.investor.key(),
+        quote_amount,
+        base_amount,
+        quote_destination: ctx.accounts.quote_destination.key(),
+        base_destination: base_destination_key,
+        ata_created,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Check that `destination` is an open token account of `mint` owned by the
+/// investor, first creating the investor's ATA from the rent reserve when it is
+/// missing and the policy allows it. Returns whether an ATA was created.
+fn prepare_destination<'info>(
+    accounts: &ClaimPendingPayout<'info>,
+    destination: &AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    rent_reserve_bump: Option<u8>
+) -> Result<bool> {
+    let investor = accounts.investor.key();
+    let mut created = false;
+
+    if destination.data_is_empty() && accounts.policy.create_payout_atas {
+        let (Some(rent_reserve), Some(associated_token_program), Some(bump)) = (
+            accounts.rent_reserve.as_ref(),
+            accounts.associated_token_program.as_ref(),
+            rent_reserve_bump,
+        ) else {
+            return err!(HonouraryError::InvalidPayoutDestination);
+        };
+        require_keys_eq!(
+            destination.key(),
+            get_associated_token_address_with_program_id(
+                &investor,
+                &mint.key(),
+                &accounts.token_program.key()
+            ),
+            HonouraryError::InvalidPayoutDestination
+        );
+
+        let vault_key = accounts.vault.key();
+        let bump_slice = [bump];
+        let rent_reserve_seeds: &[&[u8]] = &[RENT_RESERVE_SEED, vault_key.as_ref(), &bump_slice];
+
+        associated_token::create(
+            CpiContext::new_with_signer(
+                associated_token_program.to_account_info(),
+                Create {
+                    payer: rent_reserve.to_account_info(),
+                    associated_token: destination.clone(),
+                    authority: accounts.investor.to_account_info(),
+                    mint: mint.to_account_info(),
+                    system_program: accounts.system_program.to_account_info(),
+                    token_program: accounts.token_program.to_account_info(),
+                },
+                &[rent_reserve_seeds]
+            )
+        )?;
+        created = true;
+    }
+
+    require_keys_eq!(*destination.owner, accounts.token_program.key(), HonouraryError::InvalidPayoutDestination);
+    let token_account = TokenAccount::try_deserialize(&mut &destination.try_borrow_data()?[..])
+        .map_err(|_| error!(HonouraryError::InvalidPayoutDestination))?;
+    require!(
+        token_account.mint == mint.key() &&
+            token_account.owner == investor &&
+            !token_account.is_frozen(),
+        HonouraryError::InvalidPayoutDestination
+    );
+
+    Ok(created)
+}
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint,
+    TokenAccount,
+    TokenInterface,
+    transfer_checked,
+    TransferChecked,
+};
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ InvestorFeePositionOwner, PendingRewardPayout, RewardProgress },
+    utils::pda::position_owner_signer_seeds,
+    events::PendingRewardPayoutClaimed,
+};
+
+#[derive(Accounts)]
+pub struct ClaimPendingRewardPayout<'info> {
+    /// Anyone can submit a claim on the investor's behalf
+    pub claimer: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Position owner PDA (treasury authority)
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            INVESTOR_FEE_POS_OWNER_SEED
+        ],
+        bump = position_owner.bump
+    )]
+    pub position_owner: Box<Account<'info, InvestorFeePositionOwner>>,
+
+    /// Reward distribution ledger of the farm (outstanding escrow total)
+    #[account(
+        mut,
+        seeds = [REWARD_PROGRESS_SEED, vault.key().as_ref(), &[pending_reward_payout.reward_index]],
+        bump = reward_progress.bump
+    )]
+    pub reward_progress: Box<Account<'info, RewardProgress>>,
+
+    /// Investor wallet the escrow belongs to
+    /// CHECK: Only used as PDA seed and destination token account authority
+    pub investor: UncheckedAccount<'info>,
+
+    /// The investor's reward escrow
+    #[account(
+        mut,
+        seeds = [
+            PENDING_REWARD_PAYOUT_SEED,
+            vault.key().as_ref(),
+            reward_mint.key().as_ref(),
+            investor.key().as_ref()
+        ],
+        bump = pending_reward_payout.bump,
+        constraint = pending_reward_payout.investor == investor.key() @ HonouraryError::PendingPayoutMismatch
+    )]
+    pub pending_reward_payout: Box<Account<'info, PendingRewardPayout>>,
+
+    /// Reward token mint
+    #[account(constraint = reward_mint.key() == reward_progress.reward_mint @ HonouraryError::PendingPayoutMismatch)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Program-owned treasury for the reward token
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, vault.key().as_ref(), reward_mint.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = position_owner,
+        token::token_program = reward_token_program
+    )]
+    pub reward_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Any open reward-mint token account owned by the investor
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = investor,
+        token::token_program = reward_token_program,
+        constraint = !destination.is_frozen() @ HonouraryError::InvalidPayoutDestination
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Withdraw farm rewards `crank_rewards` escrowed because the investor's reward
+/// account could not receive them. The rewards can go to any reward-mint token
+/// account the investor owns.
+pub fn handle_claim_pending_reward_payout(ctx: Context<ClaimPendingRewardPayout>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let amount = ctx.accounts.pending_reward_payout.take();
+    require!(amount > 0, HonouraryError::NoPendingPayout);
+
+    let vault_key = ctx.accounts.vault.key();
+    let bump_slice = [ctx.accounts.position_owner.bump];
+    let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.reward_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.reward_treasury.to_account_info(),
+                mint: ctx.accounts.reward_mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.position_owner.to_account_info(),
+            },
+            &[&signer_seeds[..]]
+        ),
+        amount,
+        ctx.accounts.reward_mint.decimals
+    )?;
+
+    let reward_progress = &mut ctx.accounts.reward_progress;
+    reward_progress.pending_payout = reward_progress.pending_payout.saturating_sub(amount);
+
+    emit!(PendingRewardPayoutClaimed {
+        vault: vault_key,
+        reward_index: ctx.accounts.pending_reward_payout.reward_index,
+        investor: ctx.accounts.investor.key(),
+        amount,
+        destination: ctx.accounts.destination.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ Policy, DistributionProgress, DistributionEpoch },
+    events::DistributionEpochCommitted,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CommitDistributionEpochParams {
+    pub merkle_root: [u8; 32],
+    pub leaf_count: u32,
+    pub total_amount: u64, // Sum of leaf amounts, taken from the unallocated claim pool
+    pub claim_window_seconds: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CommitDistributionEpochParams)]
+pub struct CommitDistributionEpoch<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Payer for epoch account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(seeds = [POLICY_SEED, vault.key().as_ref()], bump = policy.bump)]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// Distribution progress holding the unallocated claim pool
+    #[account(
+        mut,
+        seeds = [PROGRESS_SEED, vault.key().as_ref()],
+        bump = progress.bump
+    )]
+    pub progress: Box<Account<'info, DistributionProgress>>,
+
+    /// New claim epoch
+    #[account(
+        init,
+        seeds = [
+            DISTRIBUTION_EPOCH_SEED,
+            vault.key().as_ref(),
+            &progress.next_epoch_index.to_le_bytes()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + DistributionEpoch::space(params.leaf_count)
+    )]
+    pub epoch: Box<Account<'info, DistributionEpoch>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Commit a Merkle root of `(index, investor, amount)` leaves for funds reserved
+/// by Merkle-mode days. The leaves are built off-chain from the last closed day's
+/// snapshot, whose day and total locked amount the epoch records. Claims open only
+/// after the policy's update delay, giving investors time to check the tree.
+pub fn handle_commit_distribution_epoch(
+    ctx: Context<CommitDistributionEpoch>,
+    params: CommitDistributionEpochParams,
+) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    require!(
+        params.leaf_count > 0 && params.leaf_count <= MAX_EPOCH_LEAVES,
+        HonouraryError::InvalidDistributionEpoch
+    );
+    require!(params.total_amount > 0, HonouraryError::InvalidDistributionEpoch);
+    require!(
+        params.claim_window_seconds >= MIN_CLAIM_WINDOW_SECONDS,
+        HonouraryError::InvalidDistributionEpoch
+    );
+
+    // The snapshot totals stay in place from the day's close until the next snapshot opens
+    let progress = &mut ctx.accounts.progress;
+    require!(progress.day_completed, HonouraryError::DistributionInProgress);
+    require!(
+        params.total_amount <= progress.merkle_unallocated,
+        HonouraryError::InsufficientMerklePool
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let claimable_at = current_time
+        .checked_add(ctx.accounts.policy.update_delay_seconds)
+        .ok_or(HonouraryError::MathOverflow)?;
+    let expires_at = claimable_at
+        .checked_add(params.claim_window_seconds)
+        .ok_or(HonouraryError::MathOverflow)?;
+
+    let epoch = &mut ctx.accounts.epoch;
+    epoch.vault = ctx.accounts.vault.key();
+    epoch.epoch_index = progress.next_epoch_index;
+    epoch.bump = ctx.bumps.epoch;
+    epoch.merkle_root = params.merkle_root;
+    epoch.leaf_count = params.leaf_count;
+    epoch.total_amount = params.total_amount;
+    epoch.claimed_amount = 0;
+    epoch.snapshot_day = progress.current_day;
+    epoch.snapshot_total_locked = progress.current_day_total_locked_all;
+    epoch.created_at = current_time;
+    epoch.claimable_at = claimable_at;
+    epoch.expires_at = expires_at;
+    epoch.swept = false;
+    epoch.claimed_bitmap = vec![0u8; DistributionEpoch::bitmap_len(params.leaf_count)];
+
+    progress.merkle_unallocated -= params.total_amount;
+    progress.next_epoch_index += 1;
+
+    emit!(DistributionEpochCommitted {
+        vault: ctx.accounts.vault.key(),
+        epoch_index: epoch.epoch_index,
+        merkle_root: params.merkle_root,
+        leaf_count: params.leaf_count,
+        total_amount: params.total_amount,
+        snapshot_day: epoch.snapshot_day,
+        snapshot_total_locked: epoch.snapshot_total_locked,
+        claimable_at,
+        expires_at,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 use anchor_spl::token_interface::{
+    burn,
+    Burn,
     TokenAccount,
     TokenInterface,
     transfer_checked,
@@ -9,9 +12,29 @@ use crate::cp_amm_types::{ Pool, Position };
 use crate::{
     constants::*,
     error::HonouraryError,
-    state::{ InvestorFeePositionOwner, Policy, DistributionProgress },
-    utils::math::*,
-    integrations::{ cp_amm::claim_position_fees_quote_only },
+    state::{
+        InvestorFeePositionOwner,
+        FeeCollection,
+        Policy,
+        CapOverflowMode,
+        DistributionMode,
+        DistributionProgress,
+        InvestorRegistry,
+        PaidBitmapShard,
+        PendingPayout,
+        InvestorLedger,
+        HonoraryPosition,
+    },
+    utils::{ math::*, pda::{ create_investor_pda, position_owner_signer_seeds }, token::payout_account_open },
+    integrations::cp_amm::{
+        add_liquidity,
+        claim_position_fees,
+        ClaimFeesAccounts,
+        swap_treasury,
+        AddLiquidityAccounts,
+        SwapAccounts,
+        SwapDirection,
+    },
     events::*,
 };
 
@@ -19,6 +42,8 @@ use crate::{
 #[instruction(page_start: u32, page_size: u32)]
 pub struct CrankDistribution<'info> {
     /// Anyone can call the crank (permissionless)
+    /// Pays for bitmap shard and pending payout creation the first time each is used
+    #[account(mut)]
     pub cranker: Signer<'info>,
 
     /// Vault identifier
@@ -44,19 +69,25 @@ pub struct CrankDistribution<'info> {
     )]
     pub position: Box<Account<'info, Position>>,
 
-    /// DAMM v2 pool
-    #[account(constraint = pool.key() == position_owner.pool)]
+    /// DAMM v2 pool (mutable for base fee swaps)
+    #[account(mut, constraint = pool.key() == position_owner.pool)]
     pub pool: Box<Account<'info, Pool>>,
 
     /// Pool authority
     /// CHECK: CP-AMM pool authority
     pub pool_authority: UncheckedAccount<'info>,
 
-    /// Quote mint
-    #[account(constraint = quote_mint.key() == position_owner.quote_mint)]
+    /// Quote mint (mutable for cap overflow burns)
+    #[account(mut, constraint = quote_mint.key() == position_owner.quote_mint)]
     pub quote_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
 
-    /// Base mint
+    /// Base mint (the vault's investor token vested by the Streamflow streams)
+    #[account(
+        mut,
+        constraint = base_mint.key() != quote_mint.key() @ HonouraryError::InvalidPoolConfiguration,
+        constraint = (base_mint.key() == pool.token_a_mint || base_mint.key() == pool.token_b_mint)
+            @ HonouraryError::InvalidPoolConfiguration
+    )]
     pub base_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
 
     /// Quote vault from pool
@@ -77,7 +108,7 @@ pub struct CrankDistribution<'info> {
     )]
     pub treasury_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Program-owned treasury for base tokens (should remain zero)
+    /// Program-owned treasury for base tokens (should remain zero unless BothTokens)
     #[account(
         mut,
         seeds = [TREASURY_SEED, vault.key().as_ref(), base_mint.key().as_ref()],
@@ -95,6 +126,22 @@ pub struct CrankDistribution<'info> {
     )]
     pub creator_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// Creator's base token account (BothTokens vaults without remainder recipients)
+    #[account(
+        mut,
+        token::mint = base_mint,
+        token::authority = policy.creator_wallet
+    )]
+    pub creator_base_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Cranker's quote token account for the page reward (omit to forgo it)
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = cranker
+    )]
+    pub cranker_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     /// Position NFT account
     #[account(token::mint = position_owner.position_mint, token::authority = position_owner)]
     pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
@@ -119,16 +166,52 @@ pub struct CrankDistribution<'info> {
     )]
     pub progress: Box<Account<'info, DistributionProgress>>,
 
-    /// Streamflow program
-    /// CHECK: Streamflow program ID
-    pub streamflow_program: UncheckedAccount<'info>,
+    /// Investor registry page covering this page's investors
+    #[account(
+        seeds = [
+            INVESTOR_REGISTRY_SEED,
+            vault.key().as_ref(),
+            &InvestorRegistry::page_for(page_start).to_le_bytes()
+        ],
+        bump = registry.bump
+    )]
+    pub registry: Box<Account<'info, InvestorRegistry>>,
+
+    /// Paid/snapshot bitmap shard covering this page's investors (created on first use)
+    #[account(
+        init_if_needed,
+        seeds = [
+            PAID_BITMAP_SHARD_SEED,
+            vault.key().as_ref(),
+            &PaidBitmapShard::shard_for(page_start).to_le_bytes()
+        ],
+        bump,
+        payer = cranker,
+        space = 8 + PaidBitmapShard::INIT_SPACE
+    )]
+    pub bitmap_shard: Box<Account<'info, PaidBitmapShard>>,
 
     // Program accounts
     pub cp_amm_program: Program<'info, crate::cp_amm_types::CpAmm>,
     pub token_program: Interface<'info, TokenInterface>,
-
-    // Remaining accounts: [stream_account, investor_ata] pairs for this page
-    // The number of remaining accounts should be page_size * 2
+    pub system_program: Program<'info, System>,
+
+    // Remaining accounts: investor_ata for each investor in this page
+    // The first payout page of the day starts with one group of
+    // ACCOUNTS_PER_HONORARY_POSITION accounts per additional honorary position
+    // (see claim_additional_positions), ahead of the investor accounts.
+    // Every ATA must match the registry entry at its global investor index; slots
+    // left empty by remove_investor take any placeholder accounts and are not paid.
+    // The final page appends one quote token account per policy remainder recipient.
+    // BothTokens vaults pass (quote_ata, base_account) per investor, and the final page
+    // appends all recipients' quote accounts followed by their base accounts.
+    // Next comes every page investor's InvestorLedger PDA, in page order (created on
+    // first use with the cranker paying rent).
+    // Investors whose payout account is closed, frozen or not a token account of the
+    // payout mint are paid into their PendingPayout PDA instead; one PDA per such
+    // investor with a non-zero payout is appended last, in page order.
+    // A page must not span two registry pages.
+    // Locked amounts come from the day's sealed snapshot (see snapshot_locked).
     //
     // SCALABILITY: For deployments with >5 investors, use Address Lookup Tables (ALTs)
     // to compress transaction size. ALTs enable 100+ investors per transaction.
@@ -136,14 +219,14 @@ pub struct CrankDistribution<'info> {
     // With ALTs: supports 100+ investors (account addresses compressed to 1-byte indices)
 }
 
+/// Accounts passed per additional honorary position on the first payout page
+const ACCOUNTS_PER_HONORARY_POSITION: usize = 8;
+
 pub fn handle_crank_distribution<'info>(
-    ctx: Context<'_, '_, '_, 'info, CrankDistribution<'info>>,
+    ctx: Context<'_, '_, 'info, 'info, CrankDistribution<'info>>,
     page_start: u32,
     page_size: u32
 ) -> Result<()> {
-    // Note: Compute budget must be set by the client via ComputeBudgetProgram.setComputeUnitLimit()
-    // We need ~400K units for Streamflow SDK calculations with floating-point operations
-
     let progress = &mut ctx.accounts.progress;
     let policy = &ctx.accounts.policy;
     let current_time = Clock::get()?.unix_timestamp;
@@ -153,18 +236,30 @@ pub fn handle_crank_distribution<'info>(
     msg!("Progress state: day_completed={}, cursor={}, last_ts={}, current_time={}",
         progress.day_completed, progress.pagination_cursor, progress.last_distribution_ts, current_time);
 
+    policy.require_not_paused()?;
+
     // Validate pagination parameters
     require!(page_size > 0 && page_size <= MAX_PAGE_SIZE, HonouraryError::InvalidPagination);
+    require!(page_start < policy.total_investors, HonouraryError::InvalidPagination);
+
+    // Investors covered by this page (the last page of the day may be short)
+    let investors_in_page = std::cmp::min(page_size, policy.total_investors - page_start);
+
+    // A page is validated against a single registry page
+    require!(
+        InvestorRegistry::slot_for(page_start) + investors_in_page as usize <=
+            INVESTORS_PER_REGISTRY_PAGE as usize,
+        HonouraryError::InvalidPagination
+    );
 
-    // Check if we can distribute (24-hour window or continuing same day)
-    let can_dist = progress.can_distribute(current_time);
-    msg!("can_distribute={}, condition: day_completed={}, time_check={}",
-        can_dist, progress.day_completed, current_time >= progress.last_distribution_ts + 86400);
-    require!(can_dist, HonouraryError::CrankWindowNotReached);
+    // Payouts only start once snapshot_locked has covered every investor for the day
+    require!(
+        !progress.day_completed && progress.snapshot_sealed,
+        HonouraryError::SnapshotNotSealed
+    );
 
-    // Determine if this is the first page of a new day
-    let is_first_page = (page_start == 0 && progress.day_completed) ||
-                       (page_start == 0 && progress.pagination_cursor == 0);
+    // The first payout page of the day claims the fees
+    let is_first_page = !progress.day_fees_claimed;
 
     // Calculate the previous page start for idempotent retry detection
     let previous_page_start = if progress.pagination_cursor >= page_size {
@@ -174,13 +269,13 @@ pub fn handle_crank_distribution<'info>(
     };
 
     // Detect if this is an idempotent retry of the previous page
-    let is_retry_previous_page = !progress.day_completed &&
+    let is_retry_previous_page = !is_first_page &&
                                   page_start == previous_page_start &&
                                   page_start < progress.pagination_cursor;
 
     // CRITICAL SECURITY: Enforce strict sequential pagination
     // Allow only:
-    // 1. First page of new day (page_start == 0 && day_completed)
+    // 1. First payout page of the day (page_start == 0 && fees not yet claimed)
     // 2. Sequential continuation (page_start == cursor)
     // 3. Idempotent retry of previous page (for fault tolerance)
     // Reject:
@@ -191,8 +286,7 @@ pub fn handle_crank_distribution<'info>(
         // Return early without doing anything (no-op)
         msg!("Idempotent retry detected: page_start={} matches previous page", page_start);
         return Ok(());
-    } else if !is_first_page {
-        // Not first page and not a retry - must be sequential
+    } else {
         require!(
             page_start == progress.pagination_cursor,
             HonouraryError::InvalidPaginationSequence
@@ -201,356 +295,485 @@ pub fn handle_crank_distribution<'info>(
             page_start, progress.pagination_cursor);
     }
 
-    // Save the day_completed state BEFORE we modify it
-    // We need this to determine if we're starting a new day (and thus need to process only page_size investors)
-    let is_starting_new_day = is_first_page && progress.day_completed;
-
-    // If first page of new day, claim fees and calculate TOTAL locked across ALL investors
-    // IMPORTANT: On first page, remaining_accounts MUST contain ALL investor stream accounts
-    // (not just the first page), so we can calculate the total on-chain.
-    // Subsequent pages only need their page's accounts.
-    let claimed_quote = if is_starting_new_day {
-        // Calculate total_locked_all_investors ON-CHAIN by reading ALL investor streams
-        // Note: total_locked_all_investors can be 0 if all tokens are fully unlocked
-        // In this case, all fees go to creator (handled by calculation logic)
-        let mut total_locked_all_investors = 0u64;
-
-        // Iterate through ALL investor stream accounts to calculate total
-        for i in (0..ctx.remaining_accounts.len()).step_by(2) {
-            let stream_account = &ctx.remaining_accounts[i];
-
-            // Read locked amount from this stream
-            let locked = crate::integrations::streamflow::read_locked_amount_from_stream(
-                stream_account,
-                current_time
-            )?;
+    // Base fees are either swapped into quote or distributed in base alongside quote
+    let swap_base = policy.swap_base_fees;
+    let both_tokens =
+        ctx.accounts.position_owner.fee_collection == FeeCollection::BothTokens && !swap_base;
 
-            total_locked_all_investors = total_locked_all_investors
-                .checked_add(locked)
-                .ok_or(HonouraryError::MathOverflow)?;
-        }
+    // Merkle epochs are quote-only
+    require!(
+        !(both_tokens && policy.distribution_mode == DistributionMode::MerkleClaim),
+        HonouraryError::WrongDistributionMode
+    );
 
+    // Additional positions are claimed with the primary one on the first page
+    let position_accounts_len = if is_first_page {
+        ctx.accounts.position_owner.additional_position_count as usize * ACCOUNTS_PER_HONORARY_POSITION
+    } else {
+        0
+    };
+    require!(
+        ctx.remaining_accounts.len() >= position_accounts_len,
+        HonouraryError::AccountCountMismatch
+    );
+    let (position_groups, remaining_accounts) = ctx.remaining_accounts.split_at(position_accounts_len);
+
+    // First payout page claims the day's fees; the locked total is already sealed
+    if is_first_page {
         // Claim fees from honorary position
         let vault_key = ctx.accounts.vault.key();
         let bump_slice = [ctx.accounts.position_owner.bump];
-        let signer_seeds = [
-            VAULT_SEED,
-            vault_key.as_ref(),
-            INVESTOR_FEE_POS_OWNER_SEED,
-            &bump_slice,
-        ];
+        let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
         let signer_seeds_ref = &[&signer_seeds[..]];
 
-        let claimed = claim_position_fees_quote_only(
-            &ctx.accounts.position,
-            &ctx.accounts.pool,
-            &ctx.accounts.position_owner.to_account_info(),
-            &ctx.accounts.quote_mint.to_account_info(),
-            &ctx.accounts.base_mint.to_account_info(),
-            &ctx.accounts.quote_vault.to_account_info(),
-            &ctx.accounts.base_vault.to_account_info(),
-            &ctx.accounts.treasury_ata.to_account_info(),
-            &ctx.accounts.base_treasury_ata.to_account_info(),
-            &ctx.accounts.token_program,
-            &ctx.accounts.token_program,
-            &ctx.accounts.pool_authority,
-            &ctx.accounts.position_nft_account.to_account_info(),
-            &ctx.accounts.event_authority,
-            &ctx.accounts.cp_amm_program_account,
-            &ctx.accounts.cp_amm_program.to_account_info(),
+        let (claimed, base_claimed) = claim_position_fees(
+            &ClaimFeesAccounts {
+                position: &ctx.accounts.position,
+                pool: &ctx.accounts.pool,
+                position_owner_pda: &ctx.accounts.position_owner.to_account_info(),
+                quote_mint: &ctx.accounts.quote_mint.to_account_info(),
+                base_mint: &ctx.accounts.base_mint.to_account_info(),
+                quote_vault: &ctx.accounts.quote_vault.to_account_info(),
+                base_vault: &ctx.accounts.base_vault.to_account_info(),
+                treasury_ata: &ctx.accounts.treasury_ata.to_account_info(),
+                base_treasury_ata: &ctx.accounts.base_treasury_ata.to_account_info(),
+                quote_token_program: &ctx.accounts.token_program,
+                base_token_program: &ctx.accounts.token_program,
+                pool_authority: &ctx.accounts.pool_authority,
+                position_nft_account: &ctx.accounts.position_nft_account.to_account_info(),
+                event_authority: &ctx.accounts.event_authority,
+                cp_amm_program_account: &ctx.accounts.cp_amm_program_account,
+                cp_amm_program: &ctx.accounts.cp_amm_program.to_account_info(),
+            },
+            ctx.accounts.position_owner.fee_collection == FeeCollection::BothTokens || swap_base,
             signer_seeds_ref
         )?;
 
-        // Reset progress for new day with total locked amount
-        progress.start_new_day(current_time, claimed, total_locked_all_investors);
-
         // Update position owner stats
         ctx.accounts.position_owner.total_fees_claimed += claimed;
+        ctx.accounts.position_owner.total_base_fees_claimed += base_claimed;
 
-        emit!(QuoteFeesClaimed {
-            vault: ctx.accounts.vault.key(),
-            amount: claimed,
-            timestamp: current_time,
-        });
-
-        claimed
-    } else {
-        progress.current_day_total_claimed
-    };
+        let additional_claimed = claim_additional_positions(
+            ctx.accounts,
+            position_groups,
+            signer_seeds_ref,
+            current_time
+        )?;
 
-    // On first page, remaining_accounts contains ALL investors for total calculation
-    // On subsequent pages, it contains only current page's investors
-    let total_investors_in_accounts = ctx.remaining_accounts.len() / 2;
+        // Convert the base fees (plus any base carried from earlier days) into quote
+        let mut quote_for_day = claimed
+            .checked_add(additional_claimed)
+            .ok_or(HonouraryError::MathOverflow)?;
+        let mut base_for_day = base_claimed;
+        if swap_base {
+            let base_to_swap =
+                base_claimed.saturating_add(ctx.accounts.progress.base_persistent_carry_over);
+            let expected_out = amount_out_at_sqrt_price(
+                base_to_swap,
+                ctx.accounts.pool.sqrt_price,
+                ctx.accounts.pool.token_a_mint == ctx.accounts.base_mint.key()
+            )?;
 
-    // Determine how many investors to actually distribute to on THIS page
-    // On first page of new day: min(page_size, total_investors)
-    // On subsequent pages: all provided investors (which should be <= page_size)
-    let investors_to_process = if is_starting_new_day {
-        // First page of new day: only process first page_size investors
-        std::cmp::min(page_size as usize, total_investors_in_accounts)
-    } else {
-        // Subsequent pages or continuing same day: process all provided investors
-        total_investors_in_accounts
-    };
+            // Amounts too small to produce any quote wait for a later day
+            if expected_out > 0 {
+                let swapped = swap_in_treasury(
+                    ctx.accounts,
+                    SwapDirection::BaseToQuote,
+                    base_to_swap,
+                    signer_seeds_ref
+                )?;
 
-    // Calculate is_final_page based on actual progress
-    let expected_end = page_start.checked_add(investors_to_process as u32)
-        .ok_or(HonouraryError::InvalidPagination)?;
+                ctx.accounts.progress.base_persistent_carry_over = 0;
+                quote_for_day = quote_for_day.saturating_add(swapped);
+                base_for_day = 0;
 
-    let is_final_page = (investors_to_process < page_size as usize)
-        || (expected_end >= policy.total_investors);
+                emit!(BaseFeesSwapped {
+                    vault: ctx.accounts.vault.key(),
+                    base_amount: base_to_swap,
+                    quote_amount: swapped,
+                    timestamp: current_time,
+                });
+            }
+        }
 
-    msg!("DEBUG: page_start={}, page_size={}, investors_to_process={}, expected_end={}, policy.total_investors={}, is_final_page={}, day_completed={}",
-        page_start, page_size, investors_to_process, expected_end, policy.total_investors, is_final_page, progress.day_completed);
+        // Open payouts with the day's claimed fees
+        let progress = &mut ctx.accounts.progress;
+        progress.start_payouts(quote_for_day, base_for_day);
 
-    // Parse investor data from remaining accounts (inline to avoid lifetime issues)
-    let start_idx = 0; // Always start at beginning of provided page slice
-    let end_idx = investors_to_process * 2; // End at investors we're actually distributing to
+        // Unswapped base is carried, never distributed, while swapping is on
+        if swap_base {
+            progress.base_current_day_carry_over = progress.base_current_day_total_claimed;
+        }
 
-    let mut individual_locked = Vec::new();
+        // Push-mode crankers are paid from the day's fees before investor math
+        if policy.distribution_mode == DistributionMode::Push {
+            progress.current_day_cranker_pool =
+                calculate_investor_fee_amount(quote_for_day, policy.cranker_reward_bps)?;
+        }
 
-    // Process each investor we're distributing to on THIS page
-    for i in (start_idx..end_idx).step_by(2) {
-        let stream_account = &ctx.remaining_accounts[i];
-        let _investor_ata = &ctx.remaining_accounts[i + 1];
+        emit!(QuoteFeesClaimed {
+            vault: ctx.accounts.vault.key(),
+            amount: claimed + additional_claimed,
+            timestamp: current_time,
+        });
 
-        // Read locked amount from this stream
-        let locked = crate::integrations::streamflow::read_locked_amount_from_stream(
-            stream_account,
-            current_time
-        )?;
+        if base_claimed > 0 {
+            emit!(BaseFeesClaimed {
+                vault: ctx.accounts.vault.key(),
+                amount: base_claimed,
+                timestamp: current_time,
+            });
+        }
+    }
 
-        individual_locked.push(locked);
+    // Merkle mode closes the day on the first page; investors claim later with proofs
+    if policy.distribution_mode == DistributionMode::MerkleClaim {
+        return reserve_merkle_claim_pool(ctx.accounts, remaining_accounts, current_time);
     }
 
-    // Use total locked across ALL investors (stored in progress), not just this page
-    // This ensures consistent pro-rata calculation across all pages
-    let total_locked_all_investors = progress.current_day_total_locked_all;
+    // Lazily initialize the shard and drop bits left over from an earlier day
+    let progress = &mut ctx.accounts.progress;
+    let bitmap_shard = &mut ctx.accounts.bitmap_shard;
+    if bitmap_shard.vault == Pubkey::default() {
+        bitmap_shard.vault = ctx.accounts.vault.key();
+        bitmap_shard.shard_index = PaidBitmapShard::shard_for(page_start);
+        bitmap_shard.bump = ctx.bumps.bitmap_shard;
+    }
+    bitmap_shard.sync_day(progress.current_day);
 
-    // Calculate distributions for this page using TOTAL locked amount
-    let eligible_share_bps = policy.calculate_eligible_investor_share(total_locked_all_investors);
-    let total_investor_fee = calculate_investor_fee_amount(claimed_quote, eligible_share_bps)?;
+    // Final page is the one that reaches the last registered investor
+    let investors_to_process = investors_in_page as usize;
+    let expected_end = page_start.checked_add(investors_in_page)
+        .ok_or(HonouraryError::InvalidPagination)?;
 
-    let mut page_distributed = 0u64;
-    let mut page_dust = 0u64;
+    let is_final_page = expected_end >= policy.total_investors;
 
-    // CRITICAL FIX: Handle accumulated dust from previous pages within the same day
-    // Bounty spec line 99: "carry dust to later pages/day"
-    // If accumulated dust exceeds the minimum payout threshold, distribute it pro-rata to this page
-    let carry_over_from_previous_pages = progress.current_day_carry_over;
-    let mut carry_over_distributed = 0u64;
-
-    if carry_over_from_previous_pages >= policy.min_payout_lamports {
-        // Distribute accumulated dust pro-rata to investors on this page
-        // Calculate this page's share of total locked
-        let page_locked: u64 = individual_locked.iter().sum();
-
-        if total_locked_all_investors > 0 {
-            let page_share = (carry_over_from_previous_pages as u128)
-                .saturating_mul(page_locked as u128)
-                .saturating_div(total_locked_all_investors as u128) as u64;
-
-            carry_over_distributed = page_share;
-            // This will be added to page_distributed after investor distributions
-        }
-    }
+    // remaining_accounts contains the current page's investor accounts, followed on the
+    // final page by the remainder recipients' accounts, then the investors' ledgers.
+    // BothTokens vaults pass a (quote, base) pair for every investor and recipient; an
+    // investor's base account is their base-mint ATA.
+    let accounts_per_holder = if both_tokens { 2 } else { 1 };
+    let recipient_accounts_expected = if is_final_page {
+        policy.remainder_recipients().len() * accounts_per_holder
+    } else {
+        0
+    };
+    require!(
+        remaining_accounts.len() >=
+            investors_to_process * (accounts_per_holder + 1) + recipient_accounts_expected,
+        HonouraryError::AccountCountMismatch
+    );
+    let (investor_accounts, recipient_accounts) =
+        remaining_accounts.split_at(investors_to_process * accounts_per_holder);
+    let (recipient_accounts, ledger_accounts) =
+        recipient_accounts.split_at(recipient_accounts_expected);
+    let (ledger_accounts, pending_payout_accounts) = ledger_accounts.split_at(investors_to_process);
 
-    // Reset carry_over - we'll set it to new dust at the end
-    progress.current_day_carry_over = 0;
+    msg!("DEBUG: page_start={}, page_size={}, investors_to_process={}, expected_end={}, policy.total_investors={}, is_final_page={}, day_completed={}",
+        page_start, page_size, investors_to_process, expected_end, policy.total_investors, is_final_page, progress.day_completed);
 
-    // Distribute to each investor in this page
-    for (idx, locked_amount) in individual_locked.iter().enumerate() {
-        let i = start_idx + idx * 2;
-        let _stream_account = &ctx.remaining_accounts[i];
-        let investor_ata = &ctx.remaining_accounts[i + 1];
+    let mut individual_locked = Vec::new();
+    let mut deliverable = Vec::new();
 
-        // Calculate global investor index for bitmap tracking
-        let investor_global_index = page_start.checked_add(idx as u32)
-            .ok_or(HonouraryError::MathOverflow)?;
+    // Process each investor we're distributing to on THIS page
+    for (idx, accounts) in investor_accounts.chunks(accounts_per_holder).enumerate() {
+        let investor_global_index = page_start + idx as u32;
 
         // CRITICAL SECURITY: Check if this investor has already been paid today
         // This prevents duplicate payments across different pages
         require!(
-            !progress.is_investor_paid(investor_global_index),
+            !bitmap_shard.is_investor_paid(investor_global_index),
             HonouraryError::InvestorAlreadyPaid
         );
 
-        // Calculate individual payout using TOTAL locked across all investors
-        let individual_payout = calculate_individual_payout(
-            total_investor_fee,
-            *locked_amount,
-            total_locked_all_investors // Use total across ALL investors, not just this page
-        )?;
-
-        let (final_payout, dust) = apply_dust_threshold(
-            individual_payout,
-            policy.min_payout_lamports
+        // A slot emptied by remove_investor has zero weight and is never paid
+        let Some(entry) = ctx.accounts.registry.active_entry(investor_global_index)? else {
+            individual_locked.push(0);
+            deliverable.push((Pubkey::default(), true, true));
+            continue;
+        };
+
+        // Reject any ATA that does not match the registry entry at this global index
+        require_keys_eq!(
+            accounts[0].key(),
+            entry.payout_ata,
+            HonouraryError::InvestorAccountMismatch
         );
+        let quote_open = payout_account_open(&accounts[0], ctx.accounts.quote_mint.key(), entry.recipient);
+
+        // Base payouts go to the stream recipient's base-mint ATA; only that address
+        // may be escrowed for as closed, so a cranker cannot force an escrow
+        if both_tokens {
+            require_keys_eq!(
+                accounts[1].key(),
+                get_associated_token_address_with_program_id(
+                    &entry.recipient,
+                    &ctx.accounts.base_mint.key(),
+                    &ctx.accounts.token_program.key()
+                ),
+                HonouraryError::InvestorAccountMismatch
+            );
+        }
+        let base_open = !both_tokens ||
+            payout_account_open(&accounts[1], ctx.accounts.base_mint.key(), entry.recipient);
 
-        if final_payout > 0 {
-            // Check daily cap using CURRENT progress (updated incrementally)
-            let allowed_payout = check_daily_cap(
-                progress.current_day_distributed,
-                final_payout,
-                policy.daily_cap_lamports
-            )?;
-
-            if allowed_payout > 0 {
-                // Transfer to investor
-                let vault_key = ctx.accounts.vault.key();
-                let bump_slice = [ctx.accounts.position_owner.bump];
-                let signer_seeds = [
-                    VAULT_SEED,
-                    vault_key.as_ref(),
-                    INVESTOR_FEE_POS_OWNER_SEED,
-                    &bump_slice,
-                ];
-                let signer_seeds_ref = &[&signer_seeds[..]];
-
-                transfer_checked(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.token_program.to_account_info(),
-                        TransferChecked {
-                            from: ctx.accounts.treasury_ata.to_account_info(),
-                            mint: ctx.accounts.quote_mint.to_account_info(),
-                            to: investor_ata.to_account_info(),
-                            authority: ctx.accounts.position_owner.to_account_info(),
-                        },
-                        signer_seeds_ref
-                    ),
-                    allowed_payout,
-                    ctx.accounts.quote_mint.decimals
-                )?;
+        // Locked amount captured by today's sealed snapshot
+        individual_locked.push(entry.locked_snapshot);
+        deliverable.push((entry.recipient, quote_open, base_open));
+    }
 
-                // CRITICAL FIX: Update progress.current_day_distributed immediately after each transfer
-                // This ensures the daily cap check sees the cumulative amount for subsequent investors
-                progress.current_day_distributed =
-                    progress.current_day_distributed.saturating_add(allowed_payout);
-                page_distributed = page_distributed.saturating_add(allowed_payout);
+    // Use total locked across ALL investors (stored in progress), not just this page
+    // This ensures consistent pro-rata calculation across all pages
+    let total_locked_all_investors = progress.current_day_total_locked_all;
+    let eligible_share_bps = policy.calculate_eligible_investor_share(
+        total_locked_all_investors,
+        progress.last_distribution_ts
+    );
+    let (investor_pool, base_investor_pool) = progress.investor_pools(eligible_share_bps)?;
 
-                // Mark investor as paid in bitmap to prevent duplicate payments
-                progress.mark_investor_paid(investor_global_index)?;
-            }
+    // Bounty spec line 99: "carry dust to later pages/day"
+    // Accumulated dust from previous pages is distributed pro-rata once it exceeds the
+    // minimum payout threshold. progress.current_day_distributed feeds the daily cap.
+    let quote_plan = plan_page_payouts(
+        investor_pool,
+        &individual_locked,
+        total_locked_all_investors,
+        progress.current_day_carry_over,
+        policy.min_payout_lamports,
+        progress.current_day_distributed,
+        policy.daily_cap_lamports
+    )?;
+
+    // Base fees follow the same locked-weight math with their own carry-over and cap
+    let base_plan = if both_tokens {
+        plan_page_payouts(
+            base_investor_pool,
+            &individual_locked,
+            total_locked_all_investors,
+            progress.base_current_day_carry_over,
+            policy.base_min_payout_lamports,
+            progress.base_current_day_distributed,
+            policy.base_daily_cap_lamports
+        )?
+    } else {
+        PagePayouts { payouts: vec![0; individual_locked.len()], ..Default::default() }
+    };
 
-            // Accumulate dust from cap-limited payouts
-            page_dust = page_dust.saturating_add(final_payout.saturating_sub(allowed_payout));
-        } else {
-            page_dust = page_dust.saturating_add(dust);
+    // Distribute to each investor in this page
+    let vault_key = ctx.accounts.vault.key();
+    let bump_slice = [ctx.accounts.position_owner.bump];
+    let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+
+    // The cranker's reward for this page comes out of the day's cranker pool
+    let mut cranker_reward = 0;
+    if let Some(cranker_quote_account) = &ctx.accounts.cranker_quote_account {
+        cranker_reward = progress.page_cranker_reward(
+            investors_in_page,
+            policy.total_investors,
+            policy.max_cranker_reward_per_page
+        );
+        if cranker_reward > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.treasury_ata.to_account_info(),
+                        mint: ctx.accounts.quote_mint.to_account_info(),
+                        to: cranker_quote_account.to_account_info(),
+                        authority: ctx.accounts.position_owner.to_account_info(),
+                    },
+                    &[&signer_seeds[..]]
+                ),
+                cranker_reward,
+                ctx.accounts.quote_mint.decimals
+            )?;
+            progress.current_day_cranker_paid += cranker_reward;
+            progress.total_cranker_rewards = progress.total_cranker_rewards.saturating_add(cranker_reward);
         }
     }
+    let cranker = ctx.accounts.cranker.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let mut pending_payout_accounts = pending_payout_accounts.iter();
+
+    for (idx, accounts) in investor_accounts.chunks(accounts_per_holder).enumerate() {
+        let investor_global_index = page_start + idx as u32;
+        let (investor, quote_open, base_open) = deliverable[idx];
 
-    // Do NOT override with total_investor_fee; we must respect daily cap.
-    // page_distributed already reflects the sum of actual transfers this page.
-    // progress.current_day_distributed has already been updated incrementally in the loop above
+        if quote_open && quote_plan.payouts[idx] > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.treasury_ata.to_account_info(),
+                        mint: ctx.accounts.quote_mint.to_account_info(),
+                        to: accounts[0].to_account_info(),
+                        authority: ctx.accounts.position_owner.to_account_info(),
+                    },
+                    &[&signer_seeds[..]]
+                ),
+                quote_plan.payouts[idx],
+                ctx.accounts.quote_mint.decimals
+            )?;
+        }
 
-    // Distribute accumulated dust if it exceeded threshold
-    if carry_over_distributed > 0 {
-        // Distribute proportionally to investors on this page
-        let page_locked: u64 = individual_locked.iter().sum();
+        if base_open && base_plan.payouts[idx] > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.base_treasury_ata.to_account_info(),
+                        mint: ctx.accounts.base_mint.to_account_info(),
+                        to: accounts[1].to_account_info(),
+                        authority: ctx.accounts.position_owner.to_account_info(),
+                    },
+                    &[&signer_seeds[..]]
+                ),
+                base_plan.payouts[idx],
+                ctx.accounts.base_mint.decimals
+            )?;
+        }
 
-        for (idx, locked_amount) in individual_locked.iter().enumerate() {
-            if page_locked == 0 {
-                break;
+        // Payouts the investor's accounts cannot receive wait in their escrow
+        let escrow_quote = if quote_open { 0 } else { quote_plan.payouts[idx] };
+        let escrow_base = if base_open { 0 } else { base_plan.payouts[idx] };
+        if escrow_quote > 0 || escrow_base > 0 {
+            let pending_info = pending_payout_accounts
+                .next()
+                .ok_or(HonouraryError::AccountCountMismatch)?;
+            let mut pending_payout = load_pending_payout(
+                pending_info,
+                vault_key,
+                investor,
+                &cranker,
+                &system_program
+            )?;
+            if escrow_base > 0 {
+                pending_payout.base_mint = ctx.accounts.base_mint.key();
             }
+            pending_payout.credit(escrow_quote, escrow_base, current_time)?;
+            pending_payout.exit(&crate::ID)?;
 
-            let investor_dust_share = (carry_over_distributed as u128)
-                .saturating_mul(*locked_amount as u128)
-                .saturating_div(page_locked as u128) as u64;
-
-            if investor_dust_share > 0 {
-                let i = start_idx + idx * 2;
-                let investor_ata = &ctx.remaining_accounts[i + 1];
-
-                // Transfer dust share to investor
-                let vault_key = ctx.accounts.vault.key();
-                let bump_slice = [ctx.accounts.position_owner.bump];
-                let signer_seeds = [
-                    VAULT_SEED,
-                    vault_key.as_ref(),
-                    INVESTOR_FEE_POS_OWNER_SEED,
-                    &bump_slice,
-                ];
-                let signer_seeds_ref = &[&signer_seeds[..]];
-
-                transfer_checked(
-                    CpiContext::new_with_signer(
-                        ctx.accounts.token_program.to_account_info(),
-                        TransferChecked {
-                            from: ctx.accounts.treasury_ata.to_account_info(),
-                            mint: ctx.accounts.quote_mint.to_account_info(),
-                            to: investor_ata.to_account_info(),
-                            authority: ctx.accounts.position_owner.to_account_info(),
-                        },
-                        signer_seeds_ref
-                    ),
-                    investor_dust_share,
-                    ctx.accounts.quote_mint.decimals
-                )?;
+            progress.pending_payout_quote = progress.pending_payout_quote
+                .checked_add(escrow_quote)
+                .ok_or(HonouraryError::MathOverflow)?;
+            progress.pending_payout_base = progress.pending_payout_base
+                .checked_add(escrow_base)
+                .ok_or(HonouraryError::MathOverflow)?;
 
-                progress.current_day_distributed =
-                    progress.current_day_distributed.saturating_add(investor_dust_share);
-                page_distributed = page_distributed.saturating_add(investor_dust_share);
-            }
+            emit!(PayoutEscrowed {
+                vault: vault_key,
+                investor,
+                investor_index: investor_global_index,
+                quote_amount: escrow_quote,
+                base_amount: escrow_base,
+                timestamp: current_time,
+            });
         }
 
-        // Subtract distributed dust from carry_over pool
-        // Any remainder (due to rounding) stays in carry_over for next page
-        let remaining_carry_over = carry_over_from_previous_pages.saturating_sub(carry_over_distributed);
-        page_dust = page_dust.saturating_add(remaining_carry_over);
-    } else {
-        // Carry over didn't meet threshold, add it to new dust
-        page_dust = page_dust.saturating_add(carry_over_from_previous_pages);
+        let paid = (
+            if quote_open { quote_plan.payouts[idx] } else { 0 },
+            if base_open { base_plan.payouts[idx] } else { 0 },
+        );
+        // Zero-payout days leave the ledger untouched (and uncreated)
+        if quote_plan.payouts[idx] > 0 || base_plan.payouts[idx] > 0 {
+            let mut ledger = load_investor_ledger(
+                &ledger_accounts[idx],
+                vault_key,
+                investor,
+                &cranker,
+                &system_program
+            )?;
+            ledger.record_payout(
+                progress.current_day,
+                individual_locked[idx],
+                paid,
+                (escrow_quote, escrow_base),
+                current_time
+            );
+            ledger.exit(&crate::ID)?;
+
+            emit!(InvestorPaid {
+                vault: vault_key,
+                investor,
+                investor_index: investor_global_index,
+                day: progress.current_day,
+                locked_amount: individual_locked[idx],
+                quote_amount: paid.0,
+                base_amount: paid.1,
+                quote_escrowed: escrow_quote,
+                base_escrowed: escrow_base,
+                total_quote_paid: ledger.total_quote_paid,
+                total_base_paid: ledger.total_base_paid,
+                timestamp: current_time,
+            });
+        }
+
+        // Mark investor as processed in bitmap to prevent duplicate payments
+        bitmap_shard.mark_investor_paid(investor_global_index)?;
     }
+    require!(pending_payout_accounts.next().is_none(), HonouraryError::AccountCountMismatch);
 
     // Update carry_over with accumulated dust from this page
-    progress.current_day_carry_over = page_dust;
-    progress.pagination_cursor = page_start + page_size;
-    progress.total_investor_distributed += page_distributed;
+    progress.current_day_distributed =
+        progress.current_day_distributed.saturating_add(quote_plan.distributed);
+    progress.current_day_carry_over = quote_plan.dust;
+    progress.current_day_cap_overflow =
+        progress.current_day_cap_overflow.saturating_add(quote_plan.overflow);
+    progress.total_investor_distributed += quote_plan.distributed;
+
+    progress.base_current_day_distributed =
+        progress.base_current_day_distributed.saturating_add(base_plan.distributed);
+    progress.base_current_day_carry_over = base_plan.dust;
+    progress.base_current_day_cap_overflow =
+        progress.base_current_day_cap_overflow.saturating_add(base_plan.overflow);
+    progress.total_base_investor_distributed += base_plan.distributed;
+
+    progress.pagination_cursor = page_start + investors_in_page;
 
     // On final page, close out the day and send remainder to creator
-    // Final page is automatically detected from remaining_accounts.len() and policy.total_investors
+    // Final page is automatically detected from policy.total_investors
     if is_final_page {
-        // Send remainder to creator
-        let remainder = calculate_creator_remainder(
-            claimed_quote,
-            progress.current_day_distributed,
-            progress.current_day_carry_over
-        )?;
+        let (remainder, base_remainder) = progress.creator_remainders(policy.cap_overflow_mode)?;
 
-        if remainder > 0 {
-            let vault_key = ctx.accounts.vault.key();
-            let bump_slice = [ctx.accounts.position_owner.bump];
-            let signer_seeds = [
-                VAULT_SEED,
-                vault_key.as_ref(),
-                INVESTOR_FEE_POS_OWNER_SEED,
-                &bump_slice,
-            ];
-            let signer_seeds_ref = &[&signer_seeds[..]];
+        // Reinvest the policy's compound share before paying the creator side
+        let (remainder, base_remainder) =
+            compound_creator_share(ctx.accounts, remainder, base_remainder, current_time)?;
 
-            transfer_checked(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    TransferChecked {
-                        from: ctx.accounts.treasury_ata.to_account_info(),
-                        mint: ctx.accounts.quote_mint.to_account_info(),
-                        to: ctx.accounts.creator_ata.to_account_info(),
-                        authority: ctx.accounts.position_owner.to_account_info(),
-                    },
-                    signer_seeds_ref
-                ),
-                remainder,
-                ctx.accounts.quote_mint.decimals
+        let (quote_recipient_accounts, base_recipient_accounts) = if both_tokens {
+            recipient_accounts.split_at(recipient_accounts.len() / 2)
+        } else {
+            (recipient_accounts, &recipient_accounts[recipient_accounts.len()..])
+        };
+
+        pay_creator_remainder(
+            ctx.accounts,
+            FeeToken::Quote,
+            quote_recipient_accounts,
+            remainder,
+            current_time
+        )?;
+        if both_tokens {
+            pay_creator_remainder(
+                ctx.accounts,
+                FeeToken::Base,
+                base_recipient_accounts,
+                base_remainder,
+                current_time
             )?;
         }
 
+        settle_cap_overflow(ctx.accounts, current_time)?;
+
         // Complete the day
-        progress.complete_day(remainder);
+        let progress = &mut ctx.accounts.progress;
+        progress.complete_day(remainder, base_remainder);
 
         emit!(CreatorPayoutDayClosed {
             vault: ctx.accounts.vault.key(),
             creator_amount: remainder,
             total_distributed: progress.current_day_distributed,
+            base_creator_amount: base_remainder,
+            base_total_distributed: progress.base_current_day_distributed,
             timestamp: current_time,
         });
     }
@@ -561,10 +784,655 @@ pub fn handle_crank_distribution<'info>(
         page_start,
         page_size,
         investors_paid: individual_locked.len() as u32,
-        total_paid: page_distributed,
-        dust_carried: page_dust,
+        total_paid: quote_plan.distributed,
+        dust_carried: quote_plan.dust,
+        base_total_paid: base_plan.distributed,
+        base_dust_carried: base_plan.dust,
+        cranker: ctx.accounts.cranker.key(),
+        cranker_reward,
         timestamp: current_time,
     });
 
     Ok(())
 }
+
+/// Claim the quote fees of the vault's additional honorary positions. Each one is
+/// passed as a group of accounts, in position index order:
+/// (honorary_position, pool, position, position_nft_account, quote_vault,
+/// base_vault, base_mint, base_treasury). Returns the total quote claimed.
+fn claim_additional_positions<'info>(
+    accounts: &CrankDistribution<'info>,
+    position_groups: &'info [AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+    current_time: i64
+) -> Result<u64> {
+    let vault_key = accounts.vault.key();
+    let mut total_claimed = 0u64;
+
+    for (idx, group) in position_groups.chunks(ACCOUNTS_PER_HONORARY_POSITION).enumerate() {
+        let [
+            record_info,
+            pool_info,
+            position_info,
+            position_nft_account,
+            quote_vault,
+            base_vault,
+            base_mint,
+            base_treasury,
+        ] = group else {
+            return err!(HonouraryError::AccountCountMismatch);
+        };
+
+        let mut record = Account::<HonoraryPosition>::try_from(record_info)?;
+        let pool = Account::<Pool>::try_from(pool_info)?;
+        let position = Account::<Position>::try_from(position_info)?;
+        let nft_account = InterfaceAccount::<TokenAccount>::try_from(position_nft_account)?;
+        require!(
+            record.vault == vault_key &&
+                record.index as usize == idx + 1 &&
+                pool.key() == record.pool &&
+                position.key() == record.position_account &&
+                position.nft_mint == record.position_mint &&
+                nft_account.mint == record.position_mint &&
+                nft_account.owner == accounts.position_owner.key() &&
+                base_mint.key() == record.base_mint,
+            HonouraryError::HonoraryPositionMismatch
+        );
+
+        let (expected_treasury, _) = Pubkey::find_program_address(
+            &[TREASURY_SEED, vault_key.as_ref(), base_mint.key().as_ref()],
+            &crate::ID
+        );
+        require_keys_eq!(base_treasury.key(), expected_treasury, HonouraryError::HonoraryPositionMismatch);
+
+        let (claimed, _) = claim_position_fees(
+            &ClaimFeesAccounts {
+                position: &position,
+                pool: &pool,
+                position_owner_pda: &accounts.position_owner.to_account_info(),
+                quote_mint: &accounts.quote_mint.to_account_info(),
+                base_mint,
+                quote_vault,
+                base_vault,
+                treasury_ata: &accounts.treasury_ata.to_account_info(),
+                base_treasury_ata: base_treasury,
+                quote_token_program: &accounts.token_program,
+                base_token_program: &accounts.token_program,
+                pool_authority: &accounts.pool_authority,
+                position_nft_account,
+                event_authority: &accounts.event_authority,
+                cp_amm_program_account: &accounts.cp_amm_program_account,
+                cp_amm_program: &accounts.cp_amm_program.to_account_info(),
+            },
+            false,
+            signer_seeds
+        )?;
+
+        record.total_fees_claimed = record.total_fees_claimed.saturating_add(claimed);
+        record.last_claimed_day = accounts.progress.current_day;
+        record.exit(&crate::ID)?;
+
+        total_claimed = total_claimed.checked_add(claimed).ok_or(HonouraryError::MathOverflow)?;
+
+        emit!(HonoraryPositionFeesClaimed {
+            vault: vault_key,
+            position_index: record.index,
+            pool: record.pool,
+            amount: claimed,
+            timestamp: current_time,
+        });
+    }
+
+    Ok(total_claimed)
+}
+
+/// Close a Merkle-mode day: the investor share stays in the treasury as
+/// unallocated claim pool for `commit_distribution_epoch`, and the creator
+/// receives the remainder straight away.
+fn reserve_merkle_claim_pool<'info>(
+    accounts: &mut CrankDistribution<'info>,
+    recipient_accounts: &'info [AccountInfo<'info>],
+    current_time: i64
+) -> Result<()> {
+    require!(
+        recipient_accounts.len() == accounts.policy.remainder_recipients().len(),
+        HonouraryError::AccountCountMismatch
+    );
+
+    let policy = &accounts.policy;
+    let progress = &mut accounts.progress;
+    let eligible_share_bps = policy.calculate_eligible_investor_share(
+        progress.current_day_total_locked_all,
+        progress.last_distribution_ts
+    );
+    let (investor_fee, _) = progress.investor_pools(eligible_share_bps)?;
+
+    // Same daily cap as push payouts; the excess is settled by the cap overflow mode
+    let reserved = check_daily_cap(0, investor_fee, policy.daily_cap_lamports)?;
+    progress.current_day_distributed = reserved;
+    progress.current_day_cap_overflow = investor_fee.saturating_sub(reserved);
+    progress.merkle_unallocated = progress.merkle_unallocated
+        .checked_add(reserved)
+        .ok_or(HonouraryError::MathOverflow)?;
+
+    let (remainder, _) = progress.creator_remainders(policy.cap_overflow_mode)?;
+
+    let (remainder, _) = compound_creator_share(accounts, remainder, 0, current_time)?;
+    pay_creator_remainder(accounts, FeeToken::Quote, recipient_accounts, remainder, current_time)?;
+    settle_cap_overflow(accounts, current_time)?;
+
+    let progress = &mut accounts.progress;
+    progress.complete_day(remainder, 0);
+
+    emit!(MerklePoolReserved {
+        vault: accounts.vault.key(),
+        day: progress.current_day,
+        amount: reserved,
+        unallocated_total: progress.merkle_unallocated,
+        timestamp: current_time,
+    });
+
+    emit!(CreatorPayoutDayClosed {
+        vault: accounts.vault.key(),
+        creator_amount: remainder,
+        total_distributed: progress.current_day_distributed,
+        base_creator_amount: 0,
+        base_total_distributed: 0,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Reinvest `compound_bps` of the day's creator remainder into the honorary position.
+/// Quote-only budgets are first partly swapped into base at the range's deposit ratio;
+/// the liquidity delta is the most the budget covers at the current price, and any
+/// unused tokens stay in the treasury as a reserve for the next compound.
+/// Returns the remainders left for the creator side.
+fn compound_creator_share(
+    accounts: &mut CrankDistribution,
+    remainder: u64,
+    base_remainder: u64,
+    current_time: i64
+) -> Result<(u64, u64)> {
+    let compound_bps = accounts.policy.compound_bps;
+    if compound_bps == 0 {
+        return Ok((remainder, base_remainder));
+    }
+
+    let quote_share = calculate_investor_fee_amount(remainder, compound_bps)?;
+    let base_share = calculate_investor_fee_amount(base_remainder, compound_bps)?;
+    let mut quote_budget = accounts.progress.compound_quote_reserve.saturating_add(quote_share);
+    let mut base_budget = accounts.progress.compound_base_reserve.saturating_add(base_share);
+
+    let vault_key = accounts.vault.key();
+    let bump_slice = [accounts.position_owner.bump];
+    let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+    let signer_seeds_ref = &[&signer_seeds[..]];
+
+    let quote_is_token_a = accounts.pool.token_a_mint == accounts.quote_mint.key();
+
+    // Quote-only budgets need the base side bought first
+    if base_budget == 0 && quote_budget > 0 {
+        let pool = &accounts.pool;
+        let swap_in = quote_to_swap_for_liquidity(
+            quote_budget,
+            pool.sqrt_price,
+            pool.sqrt_min_price,
+            pool.sqrt_max_price,
+            quote_is_token_a
+        )?;
+
+        if swap_in > 0 && amount_out_at_sqrt_price(swap_in, pool.sqrt_price, quote_is_token_a)? > 0 {
+            let received = swap_in_treasury(
+                accounts,
+                SwapDirection::QuoteToBase,
+                swap_in,
+                signer_seeds_ref
+            )?;
+            quote_budget -= swap_in;
+            base_budget = received;
+            accounts.pool.reload()?;
+        }
+    }
+
+    let (amount_a, amount_b) = if quote_is_token_a {
+        (quote_budget, base_budget)
+    } else {
+        (base_budget, quote_budget)
+    };
+    let liquidity_delta = max_liquidity_for_amounts(
+        amount_a,
+        amount_b,
+        accounts.pool.sqrt_price,
+        accounts.pool.sqrt_min_price,
+        accounts.pool.sqrt_max_price
+    )?;
+
+    let (mut quote_used, mut base_used) = (0, 0);
+    if liquidity_delta > 0 {
+        // Balances moved by earlier CPIs in this instruction
+        accounts.treasury_ata.reload()?;
+        accounts.base_treasury_ata.reload()?;
+        let quote_before = accounts.treasury_ata.amount;
+        let base_before = accounts.base_treasury_ata.amount;
+
+        let (token_a_account, token_b_account, token_a_vault, token_b_vault, token_a_mint, token_b_mint) =
+            if quote_is_token_a {
+                (
+                    accounts.treasury_ata.to_account_info(),
+                    accounts.base_treasury_ata.to_account_info(),
+                    accounts.quote_vault.to_account_info(),
+                    accounts.base_vault.to_account_info(),
+                    accounts.quote_mint.to_account_info(),
+                    accounts.base_mint.to_account_info(),
+                )
+            } else {
+                (
+                    accounts.base_treasury_ata.to_account_info(),
+                    accounts.treasury_ata.to_account_info(),
+                    accounts.base_vault.to_account_info(),
+                    accounts.quote_vault.to_account_info(),
+                    accounts.base_mint.to_account_info(),
+                    accounts.quote_mint.to_account_info(),
+                )
+            };
+
+        add_liquidity(
+            &AddLiquidityAccounts {
+                pool: &accounts.pool.to_account_info(),
+                position: &accounts.position.to_account_info(),
+                position_nft_account: &accounts.position_nft_account.to_account_info(),
+                position_owner_pda: &accounts.position_owner.to_account_info(),
+                token_a_account: &token_a_account,
+                token_b_account: &token_b_account,
+                token_a_vault: &token_a_vault,
+                token_b_vault: &token_b_vault,
+                token_a_mint: &token_a_mint,
+                token_b_mint: &token_b_mint,
+                token_a_program: &accounts.token_program.to_account_info(),
+                token_b_program: &accounts.token_program.to_account_info(),
+                event_authority: &accounts.event_authority,
+                cp_amm_program: &accounts.cp_amm_program.to_account_info(),
+            },
+            liquidity_delta,
+            amount_a,
+            amount_b,
+            signer_seeds_ref
+        )?;
+
+        accounts.treasury_ata.reload()?;
+        accounts.base_treasury_ata.reload()?;
+        quote_used = quote_before.saturating_sub(accounts.treasury_ata.amount);
+        base_used = base_before.saturating_sub(accounts.base_treasury_ata.amount);
+    }
+
+    let progress = &mut accounts.progress;
+    progress.compound_quote_reserve = quote_budget.saturating_sub(quote_used);
+    progress.compound_base_reserve = base_budget.saturating_sub(base_used);
+    progress.total_compounded_liquidity = progress.total_compounded_liquidity
+        .saturating_add(liquidity_delta);
+
+    emit!(PositionCompounded {
+        vault: vault_key,
+        liquidity_delta,
+        quote_amount: quote_used,
+        base_amount: base_used,
+        quote_reserve: progress.compound_quote_reserve,
+        base_reserve: progress.compound_base_reserve,
+        timestamp: current_time,
+    });
+
+    Ok((remainder - quote_share, base_remainder - base_share))
+}
+
+/// Settle the day's cap overflow under the policy mode: nothing to move for
+/// ToCreator (already in the remainder) or CarryToNextDay (stays in the treasury,
+/// owed to investors), a burn from the treasuries for Burn.
+fn settle_cap_overflow(accounts: &mut CrankDistribution, current_time: i64) -> Result<()> {
+    let mode = accounts.policy.cap_overflow_mode;
+    let quote_overflow = accounts.progress.current_day_cap_overflow;
+    let base_overflow = accounts.progress.base_current_day_cap_overflow;
+    if quote_overflow == 0 && base_overflow == 0 {
+        return Ok(());
+    }
+
+    if mode == CapOverflowMode::Burn {
+        let vault_key = accounts.vault.key();
+        let bump_slice = [accounts.position_owner.bump];
+        let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+
+        for (treasury, mint, amount) in [
+            (accounts.treasury_ata.to_account_info(), &accounts.quote_mint, quote_overflow),
+            (accounts.base_treasury_ata.to_account_info(), &accounts.base_mint, base_overflow),
+        ] {
+            if amount > 0 {
+                burn(
+                    CpiContext::new_with_signer(
+                        accounts.token_program.to_account_info(),
+                        Burn {
+                            mint: mint.to_account_info(),
+                            from: treasury,
+                            authority: accounts.position_owner.to_account_info(),
+                        },
+                        &[&signer_seeds[..]]
+                    ),
+                    amount
+                )?;
+            }
+        }
+    }
+
+    accounts.progress.settle_cap_overflow(mode);
+
+    emit!(CapOverflowSettled {
+        vault: accounts.vault.key(),
+        mode,
+        quote_amount: quote_overflow,
+        base_amount: base_overflow,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Swap between the vault treasuries within the policy's slippage bound
+fn swap_in_treasury(
+    accounts: &CrankDistribution,
+    direction: SwapDirection,
+    amount_in: u64,
+    signer_seeds: &[&[&[u8]]]
+) -> Result<u64> {
+    swap_treasury(
+        &SwapAccounts {
+            pool: &accounts.pool,
+            pool_authority: &accounts.pool_authority,
+            position_owner_pda: &accounts.position_owner.to_account_info(),
+            quote_mint: &accounts.quote_mint.to_account_info(),
+            base_mint: &accounts.base_mint.to_account_info(),
+            quote_vault: &accounts.quote_vault.to_account_info(),
+            base_vault: &accounts.base_vault.to_account_info(),
+            treasury_ata: &accounts.treasury_ata.to_account_info(),
+            base_treasury_ata: &accounts.base_treasury_ata.to_account_info(),
+            token_program: &accounts.token_program.to_account_info(),
+            event_authority: &accounts.event_authority,
+            cp_amm_program_account: &accounts.cp_amm_program_account,
+            cp_amm_program: &accounts.cp_amm_program.to_account_info(),
+        },
+        direction,
+        amount_in,
+        accounts.policy.max_swap_slippage_bps,
+        signer_seeds
+    )
+}
+
+/// Load an investor's PendingPayout escrow, creating it on first use with the
+/// cranker paying rent
+fn load_pending_payout<'info>(
+    pending_info: &'info AccountInfo<'info>,
+    vault: Pubkey,
+    investor: Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>
+) -> Result<Account<'info, PendingPayout>> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &[PendingPayout::SEEDS_PREFIX, vault.as_ref(), investor.as_ref()],
+        &crate::ID
+    );
+    require_keys_eq!(pending_info.key(), expected, HonouraryError::PendingPayoutMismatch);
+
+    if pending_info.owner == &System::id() {
+        let bump_slice = [bump];
+        create_investor_pda(
+            pending_info,
+            &[PendingPayout::SEEDS_PREFIX, vault.as_ref(), investor.as_ref(), &bump_slice],
+            8 + PendingPayout::INIT_SPACE,
+            payer,
+            system_program
+        )?;
+
+        let pending_payout = PendingPayout {
+            vault,
+            investor,
+            bump,
+            base_mint: Pubkey::default(),
+            quote_amount: 0,
+            base_amount: 0,
+            total_quote_escrowed: 0,
+            total_base_escrowed: 0,
+            last_escrowed_at: 0,
+        };
+        let mut data = pending_info.try_borrow_mut_data()?;
+        pending_payout.try_serialize(&mut &mut data[..])?;
+    }
+
+    Account::try_from(pending_info)
+}
+
+/// Load an investor's InvestorLedger, creating it on first use with the cranker
+/// paying rent
+fn load_investor_ledger<'info>(
+    ledger_info: &'info AccountInfo<'info>,
+    vault: Pubkey,
+    investor: Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>
+) -> Result<Account<'info, InvestorLedger>> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &[InvestorLedger::SEEDS_PREFIX, vault.as_ref(), investor.as_ref()],
+        &crate::ID
+    );
+    require_keys_eq!(ledger_info.key(), expected, HonouraryError::InvestorLedgerMismatch);
+
+    if ledger_info.owner == &System::id() {
+        let bump_slice = [bump];
+        create_investor_pda(
+            ledger_info,
+            &[InvestorLedger::SEEDS_PREFIX, vault.as_ref(), investor.as_ref(), &bump_slice],
+            8 + InvestorLedger::INIT_SPACE,
+            payer,
+            system_program
+        )?;
+
+        let ledger = InvestorLedger {
+            vault,
+            investor,
+            bump,
+            total_quote_paid: 0,
+            total_base_paid: 0,
+            pending_quote: 0,
+            pending_base: 0,
+            last_paid_day: 0,
+            last_paid_at: 0,
+            last_locked_amount: 0,
+            days_paid: 0,
+        };
+        let mut data = ledger_info.try_borrow_mut_data()?;
+        ledger.try_serialize(&mut &mut data[..])?;
+    }
+
+    Account::try_from(ledger_info)
+}
+
+/// Fee token a creator payout is made in
+#[derive(Clone, Copy)]
+enum FeeToken {
+    Quote,
+    Base,
+}
+
+/// Pay the creator remainder: all of it to the creator's account, or split across
+/// the policy's remainder recipients, whose token accounts are passed in order.
+fn pay_creator_remainder<'info>(
+    accounts: &CrankDistribution<'info>,
+    token: FeeToken,
+    recipient_accounts: &'info [AccountInfo<'info>],
+    remainder: u64,
+    current_time: i64
+) -> Result<()> {
+    let mint = match token {
+        FeeToken::Quote => &accounts.quote_mint,
+        FeeToken::Base => &accounts.base_mint,
+    };
+
+    let recipients = accounts.policy.remainder_recipients();
+    if recipients.is_empty() {
+        let creator_account = match token {
+            FeeToken::Quote => accounts.creator_ata.to_account_info(),
+            FeeToken::Base => accounts.creator_base_ata
+                .as_ref()
+                .ok_or(HonouraryError::AccountCountMismatch)?
+                .to_account_info(),
+        };
+        return transfer_from_treasury(accounts, token, &creator_account, remainder);
+    }
+
+    let weights: Vec<u16> = recipients.iter().map(|recipient| recipient.bps).collect();
+    let shares = split_by_bps(remainder, &weights)?;
+
+    for ((recipient, token_account), amount) in recipients.iter().zip(recipient_accounts).zip(shares) {
+        let recipient_ata = InterfaceAccount::<TokenAccount>::try_from(token_account)?;
+        require!(
+            recipient_ata.mint == mint.key() && recipient_ata.owner == recipient.wallet,
+            HonouraryError::RemainderRecipientMismatch
+        );
+
+        transfer_from_treasury(accounts, token, token_account, amount)?;
+
+        emit!(RemainderRecipientPaid {
+            vault: accounts.vault.key(),
+            mint: mint.key(),
+            recipient: recipient.wallet,
+            token_account: token_account.key(),
+            bps: recipient.bps,
+            amount,
+            timestamp: current_time,
+        });
+    }
+
+    Ok(())
+}
+
+fn transfer_from_treasury<'info>(
+    accounts: &CrankDistribution<'info>,
+    token: FeeToken,
+    to: &AccountInfo<'info>,
+    amount: u64
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let (treasury, mint) = match token {
+        FeeToken::Quote => (accounts.treasury_ata.to_account_info(), &accounts.quote_mint),
+        FeeToken::Base => (accounts.base_treasury_ata.to_account_info(), &accounts.base_mint),
+    };
+
+    let vault_key = accounts.vault.key();
+    let bump_slice = [accounts.position_owner.bump];
+    let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: treasury,
+                mint: mint.to_account_info(),
+                to: to.clone(),
+                authority: accounts.position_owner.to_account_info(),
+            },
+            &[&signer_seeds[..]]
+        ),
+        amount,
+        mint.decimals
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::InvestorEntry;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token;
+
+    fn leak_account(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> &'static AccountInfo<'static> {
+        Box::leak(
+            Box::new(
+                AccountInfo::new(
+                    Box::leak(Box::new(key)),
+                    false,
+                    true,
+                    Box::leak(Box::new(1_000_000)),
+                    Box::leak(data.into_boxed_slice()),
+                    Box::leak(Box::new(owner)),
+                    false,
+                    0
+                )
+            )
+        )
+    }
+
+    fn token_account(key: Pubkey, mint: Pubkey, owner: Pubkey) -> &'static AccountInfo<'static> {
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint,
+            owner,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }.pack_into_slice(&mut data);
+        leak_account(key, spl_token::ID, data)
+    }
+
+    #[test]
+    fn test_closed_payout_ata_is_escrowed() {
+        let quote_mint = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let stream = Pubkey::new_unique();
+        let closed_ata = Pubkey::new_unique();
+
+        let mut registry = InvestorRegistry {
+            vault: Pubkey::default(),
+            page_index: 0,
+            active_count: 2,
+            bump: 0,
+            entries: [InvestorEntry::default(); INVESTORS_PER_REGISTRY_PAGE as usize],
+        };
+        registry.entries[0] = InvestorEntry {
+            stream,
+            recipient,
+            payout_ata: closed_ata,
+            active: true,
+            locked_snapshot: 0,
+        };
+
+        // The snapshot only checks the registered keys, so a closed ATA does not block it
+        registry.verify_investor_accounts(0, &stream, &closed_ata).unwrap();
+        registry.entries[0].locked_snapshot = 600;
+
+        // A closed account is system-owned and empty; it cannot receive the payout
+        let closed = leak_account(closed_ata, System::id(), vec![]);
+        assert!(!payout_account_open(closed, quote_mint, recipient));
+        let foreign = token_account(Pubkey::new_unique(), quote_mint, Pubkey::new_unique());
+        assert!(!payout_account_open(foreign, quote_mint, recipient));
+        let open = token_account(Pubkey::new_unique(), quote_mint, recipient);
+        assert!(payout_account_open(open, quote_mint, recipient));
+
+        // The crank plans the payout as usual and escrows it for the recipient
+        let plan = plan_page_payouts(1_000, &[600, 400], 1_000, 0, 0, 0, None).unwrap();
+        let mut pending_payout = PendingPayout {
+            vault: Pubkey::default(),
+            investor: recipient,
+            bump: 0,
+            base_mint: Pubkey::default(),
+            quote_amount: 0,
+            base_amount: 0,
+            total_quote_escrowed: 0,
+            total_base_escrowed: 0,
+            last_escrowed_at: 0,
+        };
+        pending_payout.credit(plan.payouts[0], 0, 10).unwrap();
+
+        // The investor later withdraws the escrow to any account they own
+        assert_eq!(pending_payout.take(), (600, 0));
+    }
+}
@@ -0,0 +1,564 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{
+    Mint,
+    TokenAccount,
+    TokenInterface,
+    transfer_checked,
+    TransferChecked,
+};
+use crate::cp_amm_types::{ Pool, Position };
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{
+        InvestorFeePositionOwner,
+        Policy,
+        DistributionProgress,
+        InvestorRegistry,
+        PendingRewardPayout,
+        RewardProgress,
+    },
+    utils::{ math::*, pda::{ create_investor_pda, position_owner_signer_seeds }, token::payout_account_open },
+    integrations::cp_amm::{ claim_reward, ClaimRewardAccounts },
+    events::*,
+};
+
+#[derive(Accounts)]
+#[instruction(reward_index: u8, page_start: u32, page_size: u32)]
+pub struct CrankRewards<'info> {
+    /// Anyone can call the crank (permissionless)
+    /// Pays for the reward ledger, treasury and reward escrows the first time each is used
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Position owner PDA
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = position_owner.bump
+    )]
+    pub position_owner: Box<Account<'info, InvestorFeePositionOwner>>,
+
+    /// Honorary position
+    #[account(
+        mut,
+        constraint = position.key() == position_owner.position_account,
+        constraint = position.nft_mint == position_owner.position_mint
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    /// DAMM v2 pool
+    #[account(mut, constraint = pool.key() == position_owner.pool)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Pool authority
+    /// CHECK: CP-AMM pool authority
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// Position NFT account
+    #[account(token::mint = position_owner.position_mint, token::authority = position_owner)]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Reward token mint of the farm (checked against the pool's reward info)
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Farm reward vault (checked against the pool's reward info)
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Program-owned treasury for the reward token
+    #[account(
+        init_if_needed,
+        seeds = [TREASURY_SEED, vault.key().as_ref(), reward_mint.key().as_ref()],
+        bump,
+        payer = cranker,
+        token::mint = reward_mint,
+        token::authority = position_owner,
+        token::token_program = reward_token_program
+    )]
+    pub reward_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's reward token account (final page, no remainder recipients)
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = policy.creator_wallet
+    )]
+    pub creator_reward_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Event authority for CP-AMM events
+    /// CHECK: PDA derived by CP-AMM
+    pub event_authority: UncheckedAccount<'info>,
+
+    /// Program account for CP-AMM (for event CPI)
+    /// CHECK: CP-AMM program account
+    pub cp_amm_program_account: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(seeds = [POLICY_SEED, vault.key().as_ref()], bump = policy.bump)]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// Distribution progress (provides the day and its sealed snapshot)
+    #[account(seeds = [PROGRESS_SEED, vault.key().as_ref()], bump = progress.bump)]
+    pub progress: Box<Account<'info, DistributionProgress>>,
+
+    /// Investor registry page covering this page's investors
+    #[account(
+        seeds = [
+            INVESTOR_REGISTRY_SEED,
+            vault.key().as_ref(),
+            &InvestorRegistry::page_for(page_start).to_le_bytes()
+        ],
+        bump = registry.bump
+    )]
+    pub registry: Box<Account<'info, InvestorRegistry>>,
+
+    /// Reward distribution ledger for this farm (created on first use)
+    #[account(
+        init_if_needed,
+        seeds = [REWARD_PROGRESS_SEED, vault.key().as_ref(), &[reward_index]],
+        bump,
+        payer = cranker,
+        space = 8 + RewardProgress::INIT_SPACE
+    )]
+    pub reward_progress: Box<Account<'info, RewardProgress>>,
+
+    // Program accounts
+    pub cp_amm_program: Program<'info, crate::cp_amm_types::CpAmm>,
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+
+    // Remaining accounts: the stream recipient's reward-mint ATA for each investor in
+    // this page (any placeholder for a slot left empty by remove_investor). The final page appends one reward-mint account per policy remainder
+    // recipient. Investors whose ATA is missing, frozen or not a token account of the
+    // reward mint are paid into their PendingRewardPayout PDA instead; one PDA per such
+    // investor with a non-zero payout is appended last, in page order.
+    // A page must not span two registry pages.
+}
+
+/// Distribute a cp-amm farm reward earned by the honorary position.
+///
+/// Runs from the seal of the fee distribution day's snapshot until the next day's
+/// snapshot opens, and pays the same locked-weight pro-rata as `crank_distribution`,
+/// with the reward's own treasury, carry-over and escrows. The first page of the day
+/// claims the reward; a frozen reward vault is passed over with cp-amm's `skip_reward`.
+/// A reward day still unfinished when the next snapshot opens is carried into the
+/// next reward day (see `RewardProgress::begin_day`).
+pub fn handle_crank_rewards<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CrankRewards<'info>>,
+    reward_index: u8,
+    page_start: u32,
+    page_size: u32
+) -> Result<()> {
+    let policy = &ctx.accounts.policy;
+    let progress = &ctx.accounts.progress;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    policy.require_not_paused()?;
+
+    // Validate pagination parameters
+    require!(page_size > 0 && page_size <= MAX_PAGE_SIZE, HonouraryError::InvalidPagination);
+    require!(page_start < policy.total_investors, HonouraryError::InvalidPagination);
+
+    let investors_in_page = std::cmp::min(page_size, policy.total_investors - page_start);
+    require!(
+        InvestorRegistry::slot_for(page_start) + investors_in_page as usize <=
+            INVESTORS_PER_REGISTRY_PAGE as usize,
+        HonouraryError::InvalidPagination
+    );
+
+    // Rewards pay out against the fee day's sealed snapshot, which stays readable
+    // after the fee day completes until the next day's snapshot opens
+    require!(progress.snapshot_weights_available(), HonouraryError::SnapshotNotSealed);
+
+    // The farm must exist and match the passed mint and vault
+    let reward_info = ctx.accounts.pool.reward_infos
+        .get(reward_index as usize)
+        .filter(|info| info.initialized != 0)
+        .ok_or(HonouraryError::InvalidRewardIndex)?;
+    require!(
+        reward_info.mint == ctx.accounts.reward_mint.key() &&
+            reward_info.vault == ctx.accounts.reward_vault.key(),
+        HonouraryError::InvalidRewardIndex
+    );
+
+    let reward_progress = &mut ctx.accounts.reward_progress;
+    if reward_progress.vault == Pubkey::default() {
+        reward_progress.vault = ctx.accounts.vault.key();
+        reward_progress.reward_index = reward_index;
+        reward_progress.bump = ctx.bumps.reward_progress;
+        reward_progress.reward_mint = ctx.accounts.reward_mint.key();
+        reward_progress.day_completed = true;
+    }
+    require_keys_eq!(
+        reward_progress.reward_mint,
+        ctx.accounts.reward_mint.key(),
+        HonouraryError::InvalidRewardIndex
+    );
+
+    if reward_progress.day != progress.current_day {
+        reward_progress.begin_day(progress.current_day);
+    }
+    require!(!reward_progress.day_completed, HonouraryError::DistributionAlreadyComplete);
+    require!(
+        page_start == reward_progress.pagination_cursor,
+        HonouraryError::InvalidPaginationSequence
+    );
+
+    let vault_key = ctx.accounts.vault.key();
+    let bump_slice = [ctx.accounts.position_owner.bump];
+    let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+    let signer_seeds_ref = &[&signer_seeds[..]];
+
+    // First page of the day claims the reward
+    if !ctx.accounts.reward_progress.day_claimed {
+        let treasury_before = ctx.accounts.reward_treasury.amount;
+        let skip_reward = ctx.accounts.reward_vault.is_frozen();
+
+        claim_reward(
+            &ClaimRewardAccounts {
+                pool_authority: &ctx.accounts.pool_authority,
+                pool: &ctx.accounts.pool.to_account_info(),
+                position: &ctx.accounts.position.to_account_info(),
+                reward_vault: &ctx.accounts.reward_vault.to_account_info(),
+                reward_mint: &ctx.accounts.reward_mint.to_account_info(),
+                reward_treasury: &ctx.accounts.reward_treasury.to_account_info(),
+                position_nft_account: &ctx.accounts.position_nft_account.to_account_info(),
+                position_owner_pda: &ctx.accounts.position_owner.to_account_info(),
+                token_program: &ctx.accounts.reward_token_program.to_account_info(),
+                event_authority: &ctx.accounts.event_authority,
+                cp_amm_program_account: &ctx.accounts.cp_amm_program_account,
+                cp_amm_program: &ctx.accounts.cp_amm_program.to_account_info(),
+            },
+            reward_index,
+            skip_reward,
+            signer_seeds_ref
+        )?;
+
+        ctx.accounts.reward_treasury.reload()?;
+        let claimed = ctx.accounts.reward_treasury.amount.saturating_sub(treasury_before);
+        ctx.accounts.reward_progress.start_payouts(claimed);
+
+        emit!(RewardsClaimed {
+            vault: vault_key,
+            reward_index,
+            mint: ctx.accounts.reward_mint.key(),
+            amount: claimed,
+            skipped_frozen_vault: skip_reward,
+            timestamp: current_time,
+        });
+    }
+
+    let policy = &ctx.accounts.policy;
+    let progress = &ctx.accounts.progress;
+    let reward_mint_key = ctx.accounts.reward_mint.key();
+
+    let expected_end = page_start + investors_in_page;
+    let is_final_page = expected_end >= policy.total_investors;
+    let recipients = policy.remainder_recipients();
+    let investors_to_process = investors_in_page as usize;
+
+    let recipient_accounts_expected = if is_final_page { recipients.len() } else { 0 };
+    require!(
+        ctx.remaining_accounts.len() >= investors_to_process + recipient_accounts_expected,
+        HonouraryError::AccountCountMismatch
+    );
+    let (investor_accounts, recipient_accounts) =
+        ctx.remaining_accounts.split_at(investors_to_process);
+    let (recipient_accounts, pending_payout_accounts) =
+        recipient_accounts.split_at(recipient_accounts_expected);
+
+    // Reward payouts go to the stream recipient's reward-mint ATA; only that address
+    // may be escrowed for as missing, so a cranker cannot force an escrow
+    let mut individual_locked = Vec::with_capacity(investors_to_process);
+    let mut deliverable = Vec::with_capacity(investors_to_process);
+    for (idx, account) in investor_accounts.iter().enumerate() {
+        // A slot emptied by remove_investor has zero weight and is never paid
+        let Some(entry) = ctx.accounts.registry.active_entry(page_start + idx as u32)? else {
+            individual_locked.push(0);
+            deliverable.push((Pubkey::default(), true));
+            continue;
+        };
+        require_keys_eq!(
+            account.key(),
+            get_associated_token_address_with_program_id(
+                &entry.recipient,
+                &reward_mint_key,
+                &ctx.accounts.reward_token_program.key()
+            ),
+            HonouraryError::InvestorAccountMismatch
+        );
+        individual_locked.push(entry.locked_snapshot);
+        deliverable.push((entry.recipient, payout_account_open(account, reward_mint_key, entry.recipient)));
+    }
+
+    let total_locked_all_investors = progress.current_day_total_locked_all;
+    let eligible_share_bps = policy.calculate_eligible_investor_share(
+        total_locked_all_investors,
+        progress.last_distribution_ts
+    );
+
+    let reward_progress = &ctx.accounts.reward_progress;
+    let plan = plan_page_payouts(
+        calculate_investor_fee_amount(reward_progress.day_total_claimed, eligible_share_bps)?,
+        &individual_locked,
+        total_locked_all_investors,
+        reward_progress.day_carry_over,
+        policy.reward_min_payout_lamports[reward_index as usize],
+        reward_progress.day_distributed,
+        None
+    )?;
+
+    let cranker = ctx.accounts.cranker.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let mut pending_payout_accounts = pending_payout_accounts.iter();
+    let mut escrowed = 0u64;
+
+    for (idx, (account, amount)) in investor_accounts.iter().zip(&plan.payouts).enumerate() {
+        let (investor, open) = deliverable[idx];
+        if open {
+            transfer_reward(ctx.accounts, account, *amount, signer_seeds_ref)?;
+            continue;
+        }
+        if *amount == 0 {
+            continue;
+        }
+
+        // Rewards the investor's ATA cannot receive wait in their escrow
+        let pending_info = pending_payout_accounts
+            .next()
+            .ok_or(HonouraryError::AccountCountMismatch)?;
+        let mut pending_payout = load_pending_reward_payout(
+            pending_info,
+            vault_key,
+            reward_mint_key,
+            reward_index,
+            investor,
+            &cranker,
+            &system_program
+        )?;
+        pending_payout.credit(*amount, current_time)?;
+        pending_payout.exit(&crate::ID)?;
+        escrowed = escrowed.checked_add(*amount).ok_or(HonouraryError::MathOverflow)?;
+
+        emit!(RewardPayoutEscrowed {
+            vault: vault_key,
+            reward_index,
+            investor,
+            investor_index: page_start + idx as u32,
+            amount: *amount,
+            timestamp: current_time,
+        });
+    }
+    require!(pending_payout_accounts.next().is_none(), HonouraryError::AccountCountMismatch);
+
+    let reward_progress = &mut ctx.accounts.reward_progress;
+    reward_progress.pending_payout = reward_progress.pending_payout
+        .checked_add(escrowed)
+        .ok_or(HonouraryError::MathOverflow)?;
+    reward_progress.day_distributed = reward_progress.day_distributed.saturating_add(plan.distributed);
+    reward_progress.day_carry_over = plan.dust;
+    reward_progress.total_investor_distributed =
+        reward_progress.total_investor_distributed.saturating_add(plan.distributed);
+    reward_progress.pagination_cursor = expected_end;
+
+    if is_final_page {
+        let remainder = calculate_creator_remainder(
+            reward_progress.day_total_claimed,
+            reward_progress.day_distributed,
+            reward_progress.day_carry_over
+        )?;
+
+        // Creator side: the creator's account, or the policy's remainder recipients
+        if recipients.is_empty() {
+            let creator_account = ctx.accounts.creator_reward_account
+                .as_ref()
+                .ok_or(HonouraryError::AccountCountMismatch)?
+                .to_account_info();
+            transfer_reward(ctx.accounts, &creator_account, remainder, signer_seeds_ref)?;
+        } else {
+            let weights: Vec<u16> = recipients.iter().map(|recipient| recipient.bps).collect();
+            let shares = split_by_bps(remainder, &weights)?;
+
+            for ((recipient, account), amount) in recipients.iter().zip(recipient_accounts).zip(shares) {
+                let token_account = InterfaceAccount::<TokenAccount>::try_from(account)?;
+                require!(
+                    token_account.mint == reward_mint_key && token_account.owner == recipient.wallet,
+                    HonouraryError::RemainderRecipientMismatch
+                );
+                transfer_reward(ctx.accounts, account, amount, signer_seeds_ref)?;
+            }
+        }
+
+        let reward_progress = &mut ctx.accounts.reward_progress;
+        reward_progress.complete_day(remainder);
+
+        emit!(RewardPayoutDayClosed {
+            vault: vault_key,
+            reward_index,
+            creator_amount: remainder,
+            total_distributed: reward_progress.day_distributed,
+            timestamp: current_time,
+        });
+    }
+
+    emit!(RewardPayoutPage {
+        vault: vault_key,
+        reward_index,
+        page_start,
+        page_size,
+        investors_paid: individual_locked.len() as u32,
+        total_paid: plan.distributed,
+        dust_carried: plan.dust,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+fn transfer_reward<'info>(
+    accounts: &CrankRewards<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]]
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            accounts.reward_token_program.to_account_info(),
+            TransferChecked {
+                from: accounts.reward_treasury.to_account_info(),
+                mint: accounts.reward_mint.to_account_info(),
+                to: to.clone(),
+                authority: accounts.position_owner.to_account_info(),
+            },
+            signer_seeds
+        ),
+        amount,
+        accounts.reward_mint.decimals
+    )
+}
+
+/// Load an investor's PendingRewardPayout escrow for the reward mint, creating it
+/// on first use with the cranker paying rent
+fn load_pending_reward_payout<'info>(
+    pending_info: &'info AccountInfo<'info>,
+    vault: Pubkey,
+    reward_mint: Pubkey,
+    reward_index: u8,
+    investor: Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>
+) -> Result<Account<'info, PendingRewardPayout>> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &[PendingRewardPayout::SEEDS_PREFIX, vault.as_ref(), reward_mint.as_ref(), investor.as_ref()],
+        &crate::ID
+    );
+    require_keys_eq!(pending_info.key(), expected, HonouraryError::PendingPayoutMismatch);
+
+    if pending_info.owner == &System::id() {
+        let bump_slice = [bump];
+        create_investor_pda(
+            pending_info,
+            &[
+                PendingRewardPayout::SEEDS_PREFIX,
+                vault.as_ref(),
+                reward_mint.as_ref(),
+                investor.as_ref(),
+                &bump_slice,
+            ],
+            8 + PendingRewardPayout::INIT_SPACE,
+            payer,
+            system_program
+        )?;
+
+        let pending_payout = PendingRewardPayout {
+            vault,
+            investor,
+            reward_mint,
+            reward_index,
+            bump,
+            amount: 0,
+            total_escrowed: 0,
+            last_escrowed_at: 0,
+        };
+        let mut data = pending_info.try_borrow_mut_data()?;
+        pending_payout.try_serialize(&mut &mut data[..])?;
+    }
+
+    Account::try_from(pending_info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::DistributionCadence;
+
+    fn missing_account(key: Pubkey) -> &'static AccountInfo<'static> {
+        Box::leak(
+            Box::new(
+                AccountInfo::new(
+                    Box::leak(Box::new(key)),
+                    false,
+                    true,
+                    Box::leak(Box::new(0)),
+                    Box::leak(Vec::new().into_boxed_slice()),
+                    Box::leak(Box::new(System::id())),
+                    false,
+                    0
+                )
+            )
+        )
+    }
+
+    #[test]
+    fn test_missing_reward_ata_does_not_block_next_snapshot() {
+        let cadence = DistributionCadence::Seconds { period: 86_400 };
+        let mut progress = DistributionProgress::deserialize(
+            &mut &vec![0u8; DistributionProgress::INIT_SPACE][..]
+        ).unwrap();
+        let mut reward_progress = RewardProgress::deserialize(
+            &mut &vec![0u8; RewardProgress::INIT_SPACE][..]
+        ).unwrap();
+        reward_progress.day_completed = true;
+
+        progress.day_completed = true;
+        progress.begin_snapshot(&cadence, 86_400, 0);
+        progress.snapshot_sealed = true;
+        reward_progress.begin_day(progress.current_day);
+        reward_progress.start_payouts(1_000);
+
+        // The first investor never created a reward ATA: their share is escrowed
+        let reward_mint = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let missing_ata = missing_account(Pubkey::new_unique());
+        assert!(!payout_account_open(missing_ata, reward_mint, recipient));
+
+        let plan = plan_page_payouts(1_000, &[600, 400], 1_000, 0, 0, 0, None).unwrap();
+        let mut pending_payout = PendingRewardPayout::deserialize(
+            &mut &vec![0u8; PendingRewardPayout::INIT_SPACE][..]
+        ).unwrap();
+        pending_payout.credit(plan.payouts[0], 10).unwrap();
+        reward_progress.pending_payout += plan.payouts[0];
+        reward_progress.day_distributed = plan.payouts[0];
+
+        // The reward day is left unfinished; the next fee day still opens its snapshot
+        progress.complete_day(0, 0);
+        assert!(progress.can_distribute(&cadence, 2 * 86_400, 0));
+        progress.begin_snapshot(&cadence, 2 * 86_400, 0);
+        progress.snapshot_sealed = true;
+
+        // The unpaid 400 rolls into the next reward day; the escrow stays claimable
+        reward_progress.begin_day(progress.current_day);
+        reward_progress.start_payouts(0);
+        assert_eq!(reward_progress.day_total_claimed, 400);
+        assert_eq!(pending_payout.take(), 600);
+    }
+}
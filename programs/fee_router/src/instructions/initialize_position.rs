@@ -6,9 +6,9 @@ use anchor_spl::{
 use crate::cp_amm_types::Pool;
 use crate::{
     constants::*,
-    state::{InvestorFeePositionOwner},
+    state::{InvestorFeePositionOwner, FeeCollection},
     utils::{validation::preflight_position_validation, pda::position_owner_signer_seeds},
-    integrations::cp_amm::create_honorary_position,
+    integrations::cp_amm::{ create_honorary_position, CreateHonoraryPositionAccounts },
     events::HonoraryPositionInitialized,
 };
 
@@ -43,7 +43,7 @@ pub struct InitializeHonoraryPosition<'info> {
     /// Quote mint (the only token we collect fees in)
     pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// Base mint (should not collect fees in this token)
+    /// Base mint (should not collect fees in this token unless BothTokens)
     pub base_mint: Box<InterfaceAccount<'info, Mint>>,
     
     /// Position NFT mint (will be created by CP-AMM CPI)
@@ -85,7 +85,7 @@ pub struct InitializeHonoraryPosition<'info> {
     )]
     pub treasury_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Program-owned treasury for base tokens (should remain zero)
+    /// Program-owned treasury for base tokens (should remain zero unless BothTokens)
     #[account(
         init,
         seeds = [TREASURY_SEED, vault.key().as_ref(), base_mint.key().as_ref()],
@@ -109,12 +109,14 @@ pub struct InitializeHonoraryPosition<'info> {
 
 pub fn handle_initialize_honorary_position(
     ctx: Context<InitializeHonoraryPosition>,
+    fee_collection: FeeCollection,
 ) -> Result<()> {
     let pool = &ctx.accounts.pool;
 
     // Critical preflight validation: ensure pool only collects fees in quote token
+    // (unless the vault opted into both-token collection)
     // This provides a deterministic validation step before creating the position
-    preflight_position_validation(&pool, &ctx.accounts.quote_mint.key())?;
+    preflight_position_validation(&pool, &ctx.accounts.quote_mint.key(), fee_collection)?;
     
     // Initialize position owner PDA
     let position_owner = &mut ctx.accounts.position_owner_pda;
@@ -126,6 +128,9 @@ pub fn handle_initialize_honorary_position(
     position_owner.bump = ctx.bumps.position_owner_pda;
     position_owner.created_at = Clock::get()?.unix_timestamp;
     position_owner.total_fees_claimed = 0;
+    position_owner.fee_collection = fee_collection;
+    position_owner.total_base_fees_claimed = 0;
+    position_owner.additional_position_count = 0;
     
     // Create honorary position through CP-AMM CPI
     let vault_key = ctx.accounts.vault.key();
@@ -138,19 +143,21 @@ pub fn handle_initialize_honorary_position(
     
     // CP-AMM requires Token-2022 for position creation
     create_honorary_position(
-        &ctx.accounts.pool,
-        &ctx.accounts.position_owner_pda.to_account_info(),
-        &ctx.accounts.position_nft_mint.to_account_info(),
-        &ctx.accounts.position,
-        &ctx.accounts.position_nft_account.to_account_info(),
-        &ctx.accounts.pool_authority,
-        &ctx.accounts.event_authority,
-        &ctx.accounts.cp_amm_program_account,
-        &ctx.accounts.cp_amm_program.to_account_info(),
-        &ctx.accounts.system_program.to_account_info(),
-        &ctx.accounts.token_2022_program.to_account_info(),
-        &ctx.accounts.payer.to_account_info(),
-        signer_seeds_ref,
+        &CreateHonoraryPositionAccounts {
+            pool: &ctx.accounts.pool,
+            position_owner_pda: &ctx.accounts.position_owner_pda.to_account_info(),
+            position_nft_mint: &ctx.accounts.position_nft_mint.to_account_info(),
+            position: &ctx.accounts.position,
+            position_nft_account: &ctx.accounts.position_nft_account.to_account_info(),
+            pool_authority: &ctx.accounts.pool_authority,
+            event_authority: &ctx.accounts.event_authority,
+            cp_amm_program_account: &ctx.accounts.cp_amm_program_account,
+            cp_amm_program: &ctx.accounts.cp_amm_program.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            token_program: &ctx.accounts.token_2022_program.to_account_info(),
+            payer: &ctx.accounts.payer.to_account_info(),
+        },
+        signer_seeds_ref
     )?;
     
     // Emit initialization event
@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::cp_amm_types::{ Pool, Position };
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ InvestorFeePositionOwner, Policy, DistributionProgress, PositionOperation },
+    utils::{ validation::preflight_position_validation, pda::position_owner_signer_seeds },
+    integrations::cp_amm::{
+        close_position,
+        create_honorary_position,
+        ClosePositionAccounts,
+        CreateHonoraryPositionAccounts,
+    },
+    events::HonoraryPositionMigrated,
+};
+
+#[derive(Accounts)]
+pub struct MigrateHonoraryPosition<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    /// Pays for the new position and receives the old position's rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy (holds the staged operation)
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// Distribution progress (the position may only move between days)
+    #[account(seeds = [PROGRESS_SEED, vault.key().as_ref()], bump = progress.bump)]
+    pub progress: Box<Account<'info, DistributionProgress>>,
+
+    /// Position owner PDA (owns both the old and the new position)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = position_owner.bump
+    )]
+    pub position_owner: Box<Account<'info, InvestorFeePositionOwner>>,
+
+    /// Current DAMM v2 pool
+    #[account(mut, constraint = old_pool.key() == position_owner.pool)]
+    pub old_pool: Box<Account<'info, Pool>>,
+
+    /// Current honorary position (closed by this instruction)
+    #[account(mut, constraint = old_position.key() == position_owner.position_account)]
+    pub old_position: Box<Account<'info, Position>>,
+
+    /// Current position NFT mint (burned by CP-AMM)
+    /// CHECK: Address checked against the position owner
+    #[account(mut, address = position_owner.position_mint)]
+    pub old_position_nft_mint: UncheckedAccount<'info>,
+
+    /// Current position NFT account (closed by CP-AMM)
+    #[account(
+        mut,
+        token::mint = position_owner.position_mint,
+        token::authority = position_owner
+    )]
+    pub old_position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// DAMM v2 pool to move the position to
+    #[account(mut)]
+    pub new_pool: Box<Account<'info, Pool>>,
+
+    /// New position NFT mint (will be created by CP-AMM CPI)
+    /// CHECK: Must be a signer keypair, will be initialized by CP-AMM
+    #[account(mut, signer)]
+    pub new_position_nft_mint: UncheckedAccount<'info>,
+
+    /// New position NFT token account (will be created by CP-AMM CPI)
+    /// CHECK: Will be initialized by CP-AMM as a PDA
+    #[account(mut)]
+    pub new_position_nft_account: UncheckedAccount<'info>,
+
+    /// New position account (will be created by CP-AMM)
+    /// CHECK: Created by CP-AMM CPI
+    #[account(mut)]
+    pub new_position: UncheckedAccount<'info>,
+
+    /// Pool authority from CP-AMM
+    /// CHECK: CP-AMM pool authority PDA
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// Event authority for CP-AMM CPI events
+    /// CHECK: PDA for event authority, derived by CP-AMM program
+    pub event_authority: UncheckedAccount<'info>,
+
+    /// Program account for CP-AMM (needed for event_authority derivation)
+    /// CHECK: This is the CP-AMM program account
+    pub cp_amm_program_account: UncheckedAccount<'info>,
+
+    // Program accounts
+    pub cp_amm_program: Program<'info, crate::cp_amm_types::CpAmm>,
+    /// Token-2022 program for CP-AMM CPI (position NFTs are Token-2022)
+    /// CHECK: Token-2022 program
+    pub token_2022_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Move the honorary position to another pool of the same token pair, as staged
+/// with `stage_position_operation`. The old position must already be empty: its
+/// principal withdrawn with `remove_honorary_liquidity` and its fees claimed by
+/// the crank. The treasuries and distribution state carry over unchanged.
+pub fn handle_migrate_honorary_position(ctx: Context<MigrateHonoraryPosition>) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+    ctx.accounts.policy.require_not_paused()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let new_pool_key = ctx.accounts.new_pool.key();
+    ctx.accounts.policy.take_position_operation(
+        PositionOperation::Migrate { new_pool: new_pool_key },
+        current_time
+    )?;
+
+    require!(ctx.accounts.progress.day_completed, HonouraryError::DistributionInProgress);
+
+    // Same token pair, so the treasuries and the investors' payout accounts stay valid
+    let old_pool = &ctx.accounts.old_pool;
+    let new_pool = &ctx.accounts.new_pool;
+    require!(new_pool_key != old_pool.key(), HonouraryError::InvalidPoolConfiguration);
+    let mut old_mints = [old_pool.token_a_mint, old_pool.token_b_mint];
+    let mut new_mints = [new_pool.token_a_mint, new_pool.token_b_mint];
+    old_mints.sort();
+    new_mints.sort();
+    require!(old_mints == new_mints, HonouraryError::InvalidPoolConfiguration);
+
+    preflight_position_validation(
+        new_pool,
+        &ctx.accounts.position_owner.quote_mint,
+        ctx.accounts.position_owner.fee_collection
+    )?;
+
+    // cp-amm only closes empty positions; fail early with a clearer error
+    let old_position = &ctx.accounts.old_position;
+    require!(
+        old_position.unlocked_liquidity == 0 &&
+            old_position.vested_liquidity == 0 &&
+            old_position.permanent_locked_liquidity == 0 &&
+            old_position.fee_a_pending == 0 &&
+            old_position.fee_b_pending == 0 &&
+            old_position.reward_infos.iter().all(|reward| reward.reward_pendings == 0),
+        HonouraryError::PositionNotEmpty
+    );
+
+    let vault_key = ctx.accounts.vault.key();
+    let bump_slice = [ctx.accounts.position_owner.bump];
+    let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+    let signer_seeds_ref = &[&signer_seeds[..]];
+
+    close_position(
+        &ClosePositionAccounts {
+            position_nft_mint: &ctx.accounts.old_position_nft_mint,
+            position_nft_account: &ctx.accounts.old_position_nft_account.to_account_info(),
+            pool: &ctx.accounts.old_pool.to_account_info(),
+            position: &ctx.accounts.old_position.to_account_info(),
+            pool_authority: &ctx.accounts.pool_authority,
+            rent_receiver: &ctx.accounts.authority.to_account_info(),
+            position_owner_pda: &ctx.accounts.position_owner.to_account_info(),
+            token_2022_program: &ctx.accounts.token_2022_program,
+            event_authority: &ctx.accounts.event_authority,
+            cp_amm_program_account: &ctx.accounts.cp_amm_program_account,
+            cp_amm_program: &ctx.accounts.cp_amm_program.to_account_info(),
+        },
+        signer_seeds_ref
+    )?;
+
+    create_honorary_position(
+        &CreateHonoraryPositionAccounts {
+            pool: &ctx.accounts.new_pool,
+            position_owner_pda: &ctx.accounts.position_owner.to_account_info(),
+            position_nft_mint: &ctx.accounts.new_position_nft_mint.to_account_info(),
+            position: &ctx.accounts.new_position,
+            position_nft_account: &ctx.accounts.new_position_nft_account.to_account_info(),
+            pool_authority: &ctx.accounts.pool_authority,
+            event_authority: &ctx.accounts.event_authority,
+            cp_amm_program_account: &ctx.accounts.cp_amm_program_account,
+            cp_amm_program: &ctx.accounts.cp_amm_program.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            token_program: &ctx.accounts.token_2022_program.to_account_info(),
+            payer: &ctx.accounts.authority.to_account_info(),
+        },
+        signer_seeds_ref
+    )?;
+
+    let position_owner = &mut ctx.accounts.position_owner;
+    let old_pool_key = position_owner.pool;
+    let old_position_key = position_owner.position_account;
+    position_owner.pool = new_pool_key;
+    position_owner.position_mint = ctx.accounts.new_position_nft_mint.key();
+    position_owner.position_account = ctx.accounts.new_position.key();
+
+    emit!(HonoraryPositionMigrated {
+        vault: vault_key,
+        old_pool: old_pool_key,
+        old_position: old_position_key,
+        new_pool: new_pool_key,
+        new_position: position_owner.position_account,
+        new_position_mint: position_owner.position_mint,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
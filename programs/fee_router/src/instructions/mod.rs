@@ -1,9 +1,57 @@
 pub mod initialize_position;
+pub mod add_honorary_position;
 pub mod setup_policy;
 pub mod crank_distribution;
+pub mod crank_rewards;
 pub mod add_honorary_liquidity;
+pub mod register_investor;
+pub mod remove_investor;
+pub mod set_investor_schedule;
+pub mod apply_investor_schedule;
+pub mod snapshot_locked;
+pub mod update_policy;
+pub mod apply_policy_update;
+pub mod cancel_policy_update;
+pub mod propose_policy_authority;
+pub mod accept_policy_authority;
+pub mod set_policy_signers;
+pub mod commit_distribution_epoch;
+pub mod claim_distribution;
+pub mod claim_pending_payout;
+pub mod claim_pending_reward_payout;
+pub mod sweep_expired_epoch;
+pub mod stage_position_operation;
+pub mod cancel_position_operation;
+pub mod remove_honorary_liquidity;
+pub mod migrate_honorary_position;
+pub mod pause_vault;
+pub mod unpause_vault;
 
 pub use initialize_position::*;
+pub use add_honorary_position::*;
 pub use setup_policy::*;
 pub use crank_distribution::*;
-pub use add_honorary_liquidity::*;
\ No newline at end of file
+pub use crank_rewards::*;
+pub use add_honorary_liquidity::*;
+pub use register_investor::*;
+pub use remove_investor::*;
+pub use set_investor_schedule::*;
+pub use apply_investor_schedule::*;
+pub use snapshot_locked::*;
+pub use update_policy::*;
+pub use apply_policy_update::*;
+pub use cancel_policy_update::*;
+pub use propose_policy_authority::*;
+pub use accept_policy_authority::*;
+pub use set_policy_signers::*;
+pub use commit_distribution_epoch::*;
+pub use claim_distribution::*;
+pub use claim_pending_payout::*;
+pub use claim_pending_reward_payout::*;
+pub use sweep_expired_epoch::*;
+pub use stage_position_operation::*;
+pub use cancel_position_operation::*;
+pub use remove_honorary_liquidity::*;
+pub use migrate_honorary_position::*;
+pub use pause_vault::*;
+pub use unpause_vault::*;
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::Policy,
+    events::VaultPaused,
+};
+
+#[derive(Accounts)]
+pub struct PauseVault<'info> {
+    /// Guardian, policy authority, or one of the admin signers when a signer set is configured
+    pub authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+}
+
+/// Halt cranks and liquidity operations. Distribution state is left untouched,
+/// so an unfinished day resumes from its stored cursor and paid bitmap.
+pub fn handle_pause_vault(ctx: Context<PauseVault>) -> Result<()> {
+    ctx.accounts.policy.verify_guardian(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let policy = &mut ctx.accounts.policy;
+
+    require!(!policy.paused, HonouraryError::VaultPaused);
+    policy.paused = true;
+    policy.paused_at = current_time;
+
+    emit!(VaultPaused {
+        vault: ctx.accounts.vault.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
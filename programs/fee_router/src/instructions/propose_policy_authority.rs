@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    state::Policy,
+    events::PolicyAuthorityProposed,
+};
+
+#[derive(Accounts)]
+pub struct ProposePolicyAuthority<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+}
+
+/// Propose a new policy authority. The transfer only takes effect once the
+/// proposed key signs `accept_policy_authority`; proposing again replaces it.
+pub fn handle_propose_policy_authority(
+    ctx: Context<ProposePolicyAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    let policy = &mut ctx.accounts.policy;
+    policy.pending_authority = Some(new_authority);
+
+    emit!(PolicyAuthorityProposed {
+        vault: ctx.accounts.vault.key(),
+        current_authority: policy.authority,
+        proposed_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
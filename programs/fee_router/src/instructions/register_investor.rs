@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ Policy, DistributionProgress, InvestorRegistry, InvestorEntry, RegisteredLock },
+    events::InvestorRegistered,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegisterInvestorParams {
+    pub investor_index: u32, // Global investor index used by the crank pagination
+    pub stream: Pubkey, // Lock account of the policy's locked amount source
+    pub recipient: Pubkey,
+    pub payout_ata: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(params: RegisterInvestorParams)]
+pub struct RegisterInvestor<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Payer for registry page and lock marker creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// Distribution progress (registry may only change between days)
+    #[account(seeds = [PROGRESS_SEED, vault.key().as_ref()], bump = progress.bump)]
+    pub progress: Box<Account<'info, DistributionProgress>>,
+
+    /// Registry page holding the investor slot
+    #[account(
+        init_if_needed,
+        seeds = [
+            INVESTOR_REGISTRY_SEED,
+            vault.key().as_ref(),
+            &InvestorRegistry::page_for(params.investor_index).to_le_bytes()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + InvestorRegistry::INIT_SPACE
+    )]
+    pub registry: Box<Account<'info, InvestorRegistry>>,
+
+    /// Marker of the lock account (rejects registering it at a second index)
+    #[account(
+        init_if_needed,
+        seeds = [REGISTERED_LOCK_SEED, vault.key().as_ref(), params.stream.as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + RegisteredLock::INIT_SPACE
+    )]
+    pub registered_lock: Box<Account<'info, RegisteredLock>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_register_investor(
+    ctx: Context<RegisterInvestor>,
+    params: RegisterInvestorParams,
+) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    require!(ctx.accounts.progress.day_completed, HonouraryError::DistributionInProgress);
+
+    // Investor slots are bounded by the policy's declared investor count
+    require!(
+        params.investor_index < ctx.accounts.policy.total_investors,
+        HonouraryError::InvalidPagination
+    );
+
+    let registered_lock = &mut ctx.accounts.registered_lock;
+    require!(registered_lock.vault == Pubkey::default(), HonouraryError::LockAlreadyRegistered);
+    registered_lock.vault = ctx.accounts.vault.key();
+    registered_lock.stream = params.stream;
+    registered_lock.investor_index = params.investor_index;
+    registered_lock.bump = ctx.bumps.registered_lock;
+
+    let registry = &mut ctx.accounts.registry;
+
+    // Fresh page: record its identity
+    if registry.vault == Pubkey::default() {
+        registry.vault = ctx.accounts.vault.key();
+        registry.page_index = InvestorRegistry::page_for(params.investor_index);
+        registry.bump = ctx.bumps.registry;
+    }
+
+    let slot = InvestorRegistry::slot_for(params.investor_index);
+    require!(!registry.entries[slot].active, HonouraryError::InvestorAlreadyRegistered);
+
+    registry.entries[slot] = InvestorEntry {
+        stream: params.stream,
+        recipient: params.recipient,
+        payout_ata: params.payout_ata,
+        active: true,
+        locked_snapshot: 0,
+    };
+    registry.active_count += 1;
+
+    emit!(InvestorRegistered {
+        vault: ctx.accounts.vault.key(),
+        investor_index: params.investor_index,
+        stream: params.stream,
+        recipient: params.recipient,
+        payout_ata: params.payout_ata,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
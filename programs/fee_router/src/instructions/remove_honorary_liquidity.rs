@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ TokenAccount, TokenInterface, Mint };
+use crate::cp_amm_types::{ Pool, Position };
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ InvestorFeePositionOwner, Policy, PositionOperation },
+    utils::pda::position_owner_signer_seeds,
+    integrations::cp_amm::{ remove_liquidity, RemoveLiquidityAccounts },
+    events::HonoraryLiquidityRemoved,
+};
+
+#[derive(Accounts)]
+pub struct RemoveHonoraryLiquidity<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy (holds the staged operation)
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// Position owner PDA (owns the honorary position)
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = position_owner.bump
+    )]
+    pub position_owner: Box<Account<'info, InvestorFeePositionOwner>>,
+
+    /// Honorary position
+    #[account(
+        mut,
+        constraint = position.key() == position_owner.position_account,
+        constraint = position.nft_mint == position_owner.position_mint
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    /// DAMM v2 pool
+    #[account(mut, constraint = pool.key() == position_owner.pool)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Pool authority
+    /// CHECK: CP-AMM pool authority
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// Position NFT account
+    #[account(token::mint = position_owner.position_mint, token::authority = position_owner)]
+    pub position_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Quote mint
+    #[account(constraint = quote_mint.key() == position_owner.quote_mint)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Base mint
+    #[account(
+        constraint = base_mint.key() != quote_mint.key() @ HonouraryError::InvalidPoolConfiguration,
+        constraint = (base_mint.key() == pool.token_a_mint || base_mint.key() == pool.token_b_mint)
+            @ HonouraryError::InvalidPoolConfiguration
+    )]
+    pub base_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Quote vault from pool
+    #[account(mut)]
+    pub quote_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Base vault from pool
+    #[account(mut)]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives the withdrawn quote principal; never one of the fee treasuries
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        constraint = principal_quote_account.owner != position_owner.key() @ HonouraryError::InvalidPoolConfiguration
+    )]
+    pub principal_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives the withdrawn base principal; never one of the fee treasuries
+    #[account(
+        mut,
+        token::mint = base_mint,
+        constraint = principal_base_account.owner != position_owner.key() @ HonouraryError::InvalidPoolConfiguration
+    )]
+    pub principal_base_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Event authority for CP-AMM events
+    /// CHECK: PDA derived by CP-AMM
+    pub event_authority: UncheckedAccount<'info>,
+
+    /// Program account for CP-AMM (for event CPI)
+    /// CHECK: CP-AMM program account
+    pub cp_amm_program_account: UncheckedAccount<'info>,
+
+    // Program accounts
+    pub cp_amm_program: Program<'info, crate::cp_amm_types::CpAmm>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub base_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Withdraw principal from the honorary position, as staged with
+/// `stage_position_operation`. The tokens go straight to the staged principal accounts,
+/// so the fee treasuries keep holding fees only; accrued fees stay in the
+/// position until the crank claims them.
+///
+/// The thresholds are the minimum token A / token B (in pool order) to receive.
+pub fn handle_remove_honorary_liquidity(
+    ctx: Context<RemoveHonoraryLiquidity>,
+    liquidity_delta: u128,
+    token_a_amount_threshold: u64,
+    token_b_amount_threshold: u64
+) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+    ctx.accounts.policy.require_not_paused()?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    ctx.accounts.policy.take_position_operation(
+        PositionOperation::RemoveLiquidity {
+            liquidity_delta,
+            quote_destination: ctx.accounts.principal_quote_account.key(),
+            base_destination: ctx.accounts.principal_base_account.key(),
+        },
+        current_time
+    )?;
+
+    let quote_before = ctx.accounts.principal_quote_account.amount;
+    let base_before = ctx.accounts.principal_base_account.amount;
+
+    let vault_key = ctx.accounts.vault.key();
+    let bump_slice = [ctx.accounts.position_owner.bump];
+    let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+    let signer_seeds_ref = &[&signer_seeds[..]];
+
+    // Determine account ordering based on pool's token layout
+    let quote_is_token_a = ctx.accounts.pool.token_a_mint == ctx.accounts.quote_mint.key();
+
+    let (token_a_account, token_b_account) = if quote_is_token_a {
+        (&ctx.accounts.principal_quote_account, &ctx.accounts.principal_base_account)
+    } else {
+        (&ctx.accounts.principal_base_account, &ctx.accounts.principal_quote_account)
+    };
+
+    let (token_a_vault, token_b_vault) = if quote_is_token_a {
+        (&ctx.accounts.quote_vault, &ctx.accounts.base_vault)
+    } else {
+        (&ctx.accounts.base_vault, &ctx.accounts.quote_vault)
+    };
+
+    let (token_a_mint, token_b_mint) = if quote_is_token_a {
+        (&ctx.accounts.quote_mint, &ctx.accounts.base_mint)
+    } else {
+        (&ctx.accounts.base_mint, &ctx.accounts.quote_mint)
+    };
+
+    let (token_a_program, token_b_program) = if quote_is_token_a {
+        (&ctx.accounts.quote_token_program, &ctx.accounts.base_token_program)
+    } else {
+        (&ctx.accounts.base_token_program, &ctx.accounts.quote_token_program)
+    };
+
+    remove_liquidity(
+        &RemoveLiquidityAccounts {
+            pool_authority: &ctx.accounts.pool_authority,
+            pool: &ctx.accounts.pool.to_account_info(),
+            position: &ctx.accounts.position.to_account_info(),
+            position_nft_account: &ctx.accounts.position_nft_account.to_account_info(),
+            position_owner_pda: &ctx.accounts.position_owner.to_account_info(),
+            token_a_account: &token_a_account.to_account_info(),
+            token_b_account: &token_b_account.to_account_info(),
+            token_a_vault: &token_a_vault.to_account_info(),
+            token_b_vault: &token_b_vault.to_account_info(),
+            token_a_mint: &token_a_mint.to_account_info(),
+            token_b_mint: &token_b_mint.to_account_info(),
+            token_a_program: &token_a_program.to_account_info(),
+            token_b_program: &token_b_program.to_account_info(),
+            event_authority: &ctx.accounts.event_authority,
+            cp_amm_program_account: &ctx.accounts.cp_amm_program_account,
+            cp_amm_program: &ctx.accounts.cp_amm_program.to_account_info(),
+        },
+        liquidity_delta,
+        token_a_amount_threshold,
+        token_b_amount_threshold,
+        signer_seeds_ref
+    )?;
+
+    ctx.accounts.principal_quote_account.reload()?;
+    ctx.accounts.principal_base_account.reload()?;
+
+    emit!(HonoraryLiquidityRemoved {
+        vault: vault_key,
+        liquidity_delta,
+        quote_amount: ctx.accounts.principal_quote_account.amount.saturating_sub(quote_before),
+        base_amount: ctx.accounts.principal_base_account.amount.saturating_sub(base_before),
+        quote_destination: ctx.accounts.principal_quote_account.key(),
+        base_destination: ctx.accounts.principal_base_account.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
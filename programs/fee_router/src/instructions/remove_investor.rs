@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ Policy, DistributionProgress, InvestorRegistry, InvestorEntry, RegisteredLock },
+    events::InvestorRemoved,
+};
+
+#[derive(Accounts)]
+#[instruction(investor_index: u32)]
+pub struct RemoveInvestor<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// Distribution progress (registry may only change between days)
+    #[account(seeds = [PROGRESS_SEED, vault.key().as_ref()], bump = progress.bump)]
+    pub progress: Box<Account<'info, DistributionProgress>>,
+
+    /// Registry page holding the investor slot
+    #[account(
+        mut,
+        seeds = [
+            INVESTOR_REGISTRY_SEED,
+            vault.key().as_ref(),
+            &InvestorRegistry::page_for(investor_index).to_le_bytes()
+        ],
+        bump = registry.bump
+    )]
+    pub registry: Box<Account<'info, InvestorRegistry>>,
+
+    /// Marker of the slot's lock account, closed so the lock can be registered again
+    #[account(
+        mut,
+        close = rent_recipient,
+        seeds = [REGISTERED_LOCK_SEED, vault.key().as_ref(), registered_lock.stream.as_ref()],
+        bump = registered_lock.bump,
+        constraint = registered_lock.investor_index == investor_index @ HonouraryError::InvestorAccountMismatch
+    )]
+    pub registered_lock: Box<Account<'info, RegisteredLock>>,
+
+    /// Receives the lock marker's rent
+    /// CHECK: Any account chosen by the authority
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+/// Clear an investor slot. The snapshot and cranks skip the empty slot with zero
+/// weight until the authority registers it again.
+pub fn handle_remove_investor(ctx: Context<RemoveInvestor>, investor_index: u32) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    require!(ctx.accounts.progress.day_completed, HonouraryError::DistributionInProgress);
+
+    let registry = &mut ctx.accounts.registry;
+    let removed = *registry.entry(investor_index)?;
+    require_keys_eq!(
+        ctx.accounts.registered_lock.stream,
+        removed.stream,
+        HonouraryError::InvestorAccountMismatch
+    );
+
+    registry.entries[InvestorRegistry::slot_for(investor_index)] = InvestorEntry::default();
+    registry.active_count -= 1;
+
+    emit!(InvestorRemoved {
+        vault: ctx.accounts.vault.key(),
+        investor_index,
+        stream: removed.stream,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
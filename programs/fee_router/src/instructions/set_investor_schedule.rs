@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ Policy, InvestorSchedule, ScheduleTerms },
+    events::InvestorScheduleStaged,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InvestorScheduleParams {
+    pub recipient: Pubkey,
+    pub total_amount: u64,
+    pub cliff_ts: i64,
+    pub cliff_amount: u64, // Unlocked at the cliff; the rest unlocks linearly until end_ts
+    pub end_ts: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: InvestorScheduleParams)]
+pub struct SetInvestorSchedule<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Payer for schedule creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(seeds = [POLICY_SEED, vault.key().as_ref()], bump = policy.bump)]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// The investor's schedule (created on first use)
+    #[account(
+        init_if_needed,
+        seeds = [INVESTOR_SCHEDULE_SEED, vault.key().as_ref(), params.recipient.as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + InvestorSchedule::INIT_SPACE
+    )]
+    pub investor_schedule: Box<Account<'info, InvestorSchedule>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Stage new terms for an investor's native vesting schedule, creating the
+/// schedule on first use. The terms take effect with `apply_investor_schedule`
+/// once `update_delay_seconds` have passed; staging again replaces them and
+/// restarts the timelock. The schedule's address is what `register_investor`
+/// takes as the investor's lock account when the policy reads locked amounts
+/// from schedules.
+pub fn handle_set_investor_schedule(
+    ctx: Context<SetInvestorSchedule>,
+    params: InvestorScheduleParams
+) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    InvestorSchedule::validate(params.total_amount, params.cliff_ts, params.cliff_amount, params.end_ts)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let eta = current_time
+        .checked_add(ctx.accounts.policy.update_delay_seconds)
+        .ok_or(HonouraryError::MathOverflow)?;
+    let schedule = &mut ctx.accounts.investor_schedule;
+
+    // Fresh schedule: record its identity
+    if schedule.vault == Pubkey::default() {
+        schedule.vault = ctx.accounts.vault.key();
+        schedule.recipient = params.recipient;
+        schedule.bump = ctx.bumps.investor_schedule;
+        schedule.created_at = current_time;
+    }
+
+    schedule.pending_terms = Some(ScheduleTerms {
+        total_amount: params.total_amount,
+        cliff_ts: params.cliff_ts,
+        cliff_amount: params.cliff_amount,
+        end_ts: params.end_ts,
+    });
+    schedule.pending_terms_eta = eta;
+
+    emit!(InvestorScheduleStaged {
+        vault: ctx.accounts.vault.key(),
+        recipient: params.recipient,
+        schedule: schedule.key(),
+        total_amount: params.total_amount,
+        cliff_ts: params.cliff_ts,
+        cliff_amount: params.cliff_amount,
+        end_ts: params.end_ts,
+        eta,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::Policy,
+    events::PolicyAdminSignersUpdated,
+};
+
+#[derive(Accounts)]
+pub struct SetPolicySigners<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+}
+
+/// Replace the M-of-N admin signer set. Passing an empty set with a threshold
+/// of zero returns control to the single policy authority. Changing the set
+/// requires approval under the current rules.
+pub fn handle_set_policy_signers(
+    ctx: Context<SetPolicySigners>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    require!(signers.len() <= MAX_POLICY_SIGNERS, HonouraryError::InvalidAdminSignerSet);
+    require!(threshold as usize <= signers.len(), HonouraryError::InvalidAdminSignerSet);
+    require!(
+        signers.is_empty() == (threshold == 0),
+        HonouraryError::InvalidAdminSignerSet
+    );
+
+    for (i, signer) in signers.iter().enumerate() {
+        require!(*signer != Pubkey::default(), HonouraryError::InvalidAdminSignerSet);
+        require!(!signers[..i].contains(signer), HonouraryError::InvalidAdminSignerSet);
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let policy = &mut ctx.accounts.policy;
+
+    policy.admin_signers = [Pubkey::default(); MAX_POLICY_SIGNERS];
+    policy.admin_signers[..signers.len()].copy_from_slice(&signers);
+    policy.admin_signer_count = signers.len() as u8;
+    policy.admin_threshold = threshold;
+    policy.updated_at = current_time;
+
+    emit!(PolicyAdminSignersUpdated {
+        vault: ctx.accounts.vault.key(),
+        signers,
+        threshold,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
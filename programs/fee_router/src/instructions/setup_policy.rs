@@ -66,9 +66,44 @@ pub fn handle_setup_policy(
         crate::error::HonouraryError::InvalidPoolConfiguration
     );
 
+    require!(
+        params.update_delay_seconds >= 0,
+        crate::error::HonouraryError::InvalidPoolConfiguration
+    );
+
+    require!(
+        params.base_min_payout_lamports >= MIN_PAYOUT_THRESHOLD,
+        crate::error::HonouraryError::InvalidPoolConfiguration
+    );
+
+    require!(
+        params.reward_min_payout_lamports.iter().all(|&threshold| threshold >= MIN_PAYOUT_THRESHOLD),
+        crate::error::HonouraryError::InvalidPoolConfiguration
+    );
+
+    require!(
+        params.max_swap_slippage_bps <= BASIS_POINTS_DIVISOR as u16,
+        crate::error::HonouraryError::InvalidPoolConfiguration
+    );
+
+    require!(
+        params.compound_bps <= BASIS_POINTS_DIVISOR as u16,
+        crate::error::HonouraryError::InvalidPoolConfiguration
+    );
+
+    require!(
+        params.cranker_reward_bps <= BASIS_POINTS_DIVISOR as u16,
+        crate::error::HonouraryError::InvalidPoolConfiguration
+    );
+
+    params.cadence.validate()?;
+    Policy::validate_remainder_recipients(&params.remainder_recipients)?;
+    Policy::validate_share_curve(&params.share_curve)?;
+
     // Initialize policy
     let policy = &mut ctx.accounts.policy;
     policy.vault = ctx.accounts.vault.key();
+    policy.authority = ctx.accounts.authority.key();
     policy.creator_wallet = params.creator_wallet;
     policy.investor_fee_share_bps = params.investor_fee_share_bps;
     policy.daily_cap_lamports = params.daily_cap_lamports;
@@ -78,6 +113,34 @@ pub fn handle_setup_policy(
     policy.bump = ctx.bumps.policy;
     policy.created_at = Clock::get()?.unix_timestamp;
     policy.updated_at = Clock::get()?.unix_timestamp;
+    policy.update_delay_seconds = params.update_delay_seconds;
+    policy.pending_update = None;
+    policy.pending_update_eta = 0;
+    policy.pending_position_operation = None;
+    policy.pending_position_operation_eta = 0;
+    policy.pending_authority = None;
+    policy.admin_signers = [Pubkey::default(); MAX_POLICY_SIGNERS];
+    policy.admin_signer_count = 0;
+    policy.admin_threshold = 0;
+    policy.distribution_mode = params.distribution_mode;
+    policy.cadence = params.cadence;
+    policy.store_remainder_recipients(&params.remainder_recipients);
+    policy.base_daily_cap_lamports = params.base_daily_cap_lamports;
+    policy.base_min_payout_lamports = params.base_min_payout_lamports;
+    policy.swap_base_fees = params.swap_base_fees;
+    policy.max_swap_slippage_bps = params.max_swap_slippage_bps;
+    policy.compound_bps = params.compound_bps;
+    policy.create_payout_atas = params.create_payout_atas;
+    policy.cap_overflow_mode = params.cap_overflow_mode;
+    policy.guardian = params.guardian;
+    policy.paused = false;
+    policy.paused_at = 0;
+    policy.cranker_reward_bps = params.cranker_reward_bps;
+    policy.max_cranker_reward_per_page = params.max_cranker_reward_per_page;
+    policy.locked_amount_source = params.locked_amount_source;
+    policy.weighting_mode = params.weighting_mode;
+    policy.store_share_curve(&params.share_curve);
+    policy.reward_min_payout_lamports = params.reward_min_payout_lamports;
     
     // Initialize progress tracking
     let progress = &mut ctx.accounts.progress;
@@ -94,7 +157,37 @@ pub fn handle_setup_policy(
     progress.total_creator_distributed = 0;
     progress.current_day_total_locked_all = 0;
     progress.persistent_carry_over = 0;
-    progress.paid_investor_bitmap = [0u8; 256]; // Initialize bitmap as all zeros
+    progress.current_day = 0;
+    progress.snapshot_cursor = 0;
+    progress.snapshot_sealed = false;
+    progress.day_fees_claimed = false;
+    progress.merkle_unallocated = 0;
+    progress.next_epoch_index = 0;
+    progress.last_distribution_slot = 0;
+    progress.base_current_day_total_claimed = 0;
+    progress.base_current_day_distributed = 0;
+    progress.base_current_day_carry_over = 0;
+    progress.base_persistent_carry_over = 0;
+    progress.total_base_investor_distributed = 0;
+    progress.total_base_creator_distributed = 0;
+    progress.compound_quote_reserve = 0;
+    progress.compound_base_reserve = 0;
+    progress.total_compounded_liquidity = 0;
+    progress.pending_payout_quote = 0;
+    progress.pending_payout_base = 0;
+    progress.current_day_cap_overflow = 0;
+    progress.base_current_day_cap_overflow = 0;
+    progress.current_day_overflow_carry_in = 0;
+    progress.base_current_day_overflow_carry_in = 0;
+    progress.investor_overflow_carry = 0;
+    progress.base_investor_overflow_carry = 0;
+    progress.total_cap_overflow_burned = 0;
+    progress.total_base_cap_overflow_burned = 0;
+    progress.current_day_cranker_pool = 0;
+    progress.current_day_cranker_paid = 0;
+    progress.total_cranker_rewards = 0;
+    progress.previous_distribution_ts = 0;
+    progress.previous_distribution_slot = 0;
     
     emit!(PolicySetup {
         vault: ctx.accounts.vault.key(),
@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+use crate::cp_amm_types::Pool;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{
+        InvestorFeePositionOwner,
+        Policy,
+        DistributionProgress,
+        InvestorRegistry,
+        PaidBitmapShard,
+        WeightingMode,
+        DistributionMode,
+    },
+    integrations::{
+        streamflow::parse_investor_accounts,
+        locked_source::{ lock_accounts_per_investor, read_locked_amount, LockSnapshotContext },
+    },
+    events::LockedSnapshotPage,
+};
+
+#[derive(Accounts)]
+#[instruction(page_start: u32, page_size: u32)]
+pub struct SnapshotLocked<'info> {
+    /// Anyone can call the snapshot (permissionless)
+    /// Pays for bitmap shard creation the first time a shard is used
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Position owner PDA
+    #[account(
+        seeds = [VAULT_SEED, vault.key().as_ref(), INVESTOR_FEE_POS_OWNER_SEED],
+        bump = position_owner.bump
+    )]
+    pub position_owner: Box<Account<'info, InvestorFeePositionOwner>>,
+
+    /// DAMM v2 pool
+    #[account(constraint = pool.key() == position_owner.pool)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    /// Quote mint
+    #[account(constraint = quote_mint.key() == position_owner.quote_mint)]
+    pub quote_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+
+    /// Base mint (the vault's investor token, vested by Streamflow streams)
+    #[account(
+        constraint = base_mint.key() != quote_mint.key() @ HonouraryError::InvalidPoolConfiguration,
+        constraint = (base_mint.key() == pool.token_a_mint || base_mint.key() == pool.token_b_mint)
+            @ HonouraryError::InvalidPoolConfiguration
+    )]
+    pub base_mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+
+    /// Distribution policy
+    #[account(seeds = [POLICY_SEED, vault.key().as_ref()], bump = policy.bump)]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// Distribution progress tracking
+    #[account(
+        mut,
+        seeds = [PROGRESS_SEED, vault.key().as_ref()],
+        bump = progress.bump
+    )]
+    pub progress: Box<Account<'info, DistributionProgress>>,
+
+    /// Investor registry page covering this page's investors
+    #[account(
+        mut,
+        seeds = [
+            INVESTOR_REGISTRY_SEED,
+            vault.key().as_ref(),
+            &InvestorRegistry::page_for(page_start).to_le_bytes()
+        ],
+        bump = registry.bump
+    )]
+    pub registry: Box<Account<'info, InvestorRegistry>>,
+
+    /// Paid/snapshot bitmap shard covering this page's investors (created on first use)
+    #[account(
+        init_if_needed,
+        seeds = [
+            PAID_BITMAP_SHARD_SEED,
+            vault.key().as_ref(),
+            &PaidBitmapShard::shard_for(page_start).to_le_bytes()
+        ],
+        bump,
+        payer = cranker,
+        space = 8 + PaidBitmapShard::INIT_SPACE
+    )]
+    pub bitmap_shard: Box<Account<'info, PaidBitmapShard>>,
+
+    pub system_program: Program<'info, System>,
+
+    // Remaining accounts: [lock_account, investor_ata] pairs for this page, or lock
+    // accounts alone for MerkleClaim vaults (which never pay to a registered ATA).
+    // Lock accounts are of the policy's locked amount source; a cp-amm vesting is
+    // followed by its position and the token account holding the position NFT
+    // (see lock_accounts_per_investor). Every lock and payout account must match
+    // the registry entry at its global investor index. Slots left empty by
+    // remove_investor take any placeholder accounts and get zero weight.
+    // A page must not span two registry pages.
+}
+
+/// Accumulate investor locked amounts for the day over sequential pages.
+///
+/// The first page of a new day opens the snapshot (once the distribution window has
+/// elapsed). Every locked amount is read at the day's start timestamp, so the total
+/// is consistent no matter how many transactions the snapshot spans. Once the cursor
+/// reaches `total_investors` the snapshot is sealed and `crank_distribution` may pay out.
+pub fn handle_snapshot_locked<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SnapshotLocked<'info>>,
+    page_start: u32,
+    page_size: u32
+) -> Result<()> {
+    let progress = &mut ctx.accounts.progress;
+    let policy = &ctx.accounts.policy;
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    policy.require_not_paused()?;
+
+    // Validate pagination parameters
+    require!(page_size > 0 && page_size <= MAX_PAGE_SIZE, HonouraryError::InvalidPagination);
+    require!(page_start < policy.total_investors, HonouraryError::InvalidPagination);
+
+    let investors_in_page = std::cmp::min(page_size, policy.total_investors - page_start);
+    require!(
+        InvestorRegistry::slot_for(page_start) + investors_in_page as usize <=
+            INVESTORS_PER_REGISTRY_PAGE as usize,
+        HonouraryError::InvalidPagination
+    );
+
+    if progress.day_completed {
+        // First snapshot page opens a new day
+        require!(
+            progress.can_distribute(&policy.cadence, current_time, clock.slot),
+            HonouraryError::CrankWindowNotReached
+        );
+        require!(page_start == 0, HonouraryError::InvalidPaginationSequence);
+
+        progress.begin_snapshot(&policy.cadence, current_time, clock.slot);
+    } else {
+        require!(!progress.snapshot_sealed, HonouraryError::SnapshotAlreadySealed);
+
+        // Idempotent retry of the previous page - the bitmap already covers it
+        if page_start < progress.snapshot_cursor &&
+            page_start + investors_in_page == progress.snapshot_cursor
+        {
+            msg!("Idempotent snapshot retry detected: page_start={}", page_start);
+            return Ok(());
+        }
+
+        require!(
+            page_start == progress.snapshot_cursor,
+            HonouraryError::InvalidPaginationSequence
+        );
+    }
+
+    let with_payout_accounts = policy.distribution_mode == DistributionMode::Push;
+    let lock_accounts = lock_accounts_per_investor(policy.locked_amount_source);
+    let accounts_per_investor = lock_accounts + if with_payout_accounts { 1 } else { 0 };
+    require!(
+        ctx.remaining_accounts.len() == (investors_in_page as usize) * accounts_per_investor,
+        HonouraryError::AccountCountMismatch
+    );
+
+    let investors = parse_investor_accounts(
+        ctx.remaining_accounts,
+        0,
+        investors_in_page,
+        lock_accounts,
+        with_payout_accounts
+    )?;
+
+    // Lazily initialize the shard and drop bits left over from an earlier day
+    let bitmap_shard = &mut ctx.accounts.bitmap_shard;
+    if bitmap_shard.vault == Pubkey::default() {
+        bitmap_shard.vault = ctx.accounts.vault.key();
+        bitmap_shard.shard_index = PaidBitmapShard::shard_for(page_start);
+        bitmap_shard.bump = ctx.bumps.bitmap_shard;
+    }
+    bitmap_shard.sync_day(progress.current_day);
+
+    // All pages read locked amounts at the same instant: the day's start.
+    // Time-weighted weights average over the period since the previous day's start
+    // (the first day has no such period and uses the instant reading).
+    let average_from = (policy.weighting_mode == WeightingMode::TimeWeighted &&
+        progress.previous_distribution_ts > 0)
+        .then_some((progress.previous_distribution_ts, progress.previous_distribution_slot));
+    let lock_snapshot = LockSnapshotContext {
+        vault: ctx.accounts.vault.key(),
+        pool: ctx.accounts.pool.key(),
+        investor_mint: ctx.accounts.base_mint.key(),
+        timestamp: progress.last_distribution_ts,
+        slot: progress.last_distribution_slot,
+        pool_activation_type: ctx.accounts.pool.activation_type,
+        average_from,
+    };
+    let registry = &mut ctx.accounts.registry;
+    let mut page_locked = 0u64;
+
+    for (idx, investor) in investors.iter().enumerate() {
+        let investor_index = page_start + idx as u32;
+
+        require!(
+            !bitmap_shard.is_investor_snapshotted(investor_index),
+            HonouraryError::InvestorAlreadySnapshotted
+        );
+
+        // A slot emptied by remove_investor has zero weight; its accounts are not read
+        let Some(entry) = registry.active_entry(investor_index)? else {
+            bitmap_shard.mark_investor_snapshotted(investor_index)?;
+            continue;
+        };
+        let recipient = entry.recipient;
+
+        // Reject any account that does not match the registry entry at this global index.
+        // Whether the payout account can receive tokens is left to the crank, which
+        // escrows payouts for closed or frozen accounts instead of failing the day.
+        match investor.investor_ata {
+            Some(investor_ata) => registry.verify_investor_accounts(
+                investor_index,
+                investor.stream_account.key,
+                investor_ata.key
+            )?,
+            None => registry.verify_lock_account(investor_index, investor.stream_account.key)?,
+        }
+
+        let locked = read_locked_amount(
+            policy.locked_amount_source,
+            investor.stream_account,
+            investor.lock_extras,
+            &recipient,
+            &lock_snapshot
+        )?;
+        registry.entries[InvestorRegistry::slot_for(investor_index)].locked_snapshot = locked;
+        bitmap_shard.mark_investor_snapshotted(investor_index)?;
+
+        page_locked = page_locked.checked_add(locked).ok_or(HonouraryError::MathOverflow)?;
+    }
+
+    progress.current_day_total_locked_all = progress.current_day_total_locked_all
+        .checked_add(page_locked)
+        .ok_or(HonouraryError::MathOverflow)?;
+    progress.snapshot_cursor = page_start + investors_in_page;
+
+    // Seal once every investor is covered
+    if progress.snapshot_cursor >= policy.total_investors {
+        progress.snapshot_sealed = true;
+    }
+
+    emit!(LockedSnapshotPage {
+        vault: ctx.accounts.vault.key(),
+        page_start,
+        investors_snapshotted: investors_in_page,
+        page_locked,
+        total_locked: progress.current_day_total_locked_all,
+        sealed: progress.snapshot_sealed,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ Policy, PositionOperation },
+    events::PositionOperationStaged,
+};
+
+#[derive(Accounts)]
+pub struct StagePositionOperation<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+}
+
+/// Stage a liquidity withdrawal or migration of the honorary position. It can be
+/// executed once `update_delay_seconds` have passed. Staging again replaces the
+/// pending operation and restarts the timelock.
+pub fn handle_stage_position_operation(
+    ctx: Context<StagePositionOperation>,
+    operation: PositionOperation
+) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    match operation {
+        PositionOperation::RemoveLiquidity { liquidity_delta, .. } => {
+            require!(liquidity_delta > 0, HonouraryError::InvalidPoolConfiguration);
+        }
+        PositionOperation::Migrate { new_pool } => {
+            require!(new_pool != Pubkey::default(), HonouraryError::InvalidPoolConfiguration);
+        }
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let policy = &mut ctx.accounts.policy;
+
+    let eta = current_time
+        .checked_add(policy.update_delay_seconds)
+        .ok_or(HonouraryError::MathOverflow)?;
+
+    policy.pending_position_operation = Some(operation);
+    policy.pending_position_operation_eta = eta;
+
+    emit!(PositionOperationStaged {
+        vault: ctx.accounts.vault.key(),
+        authority: ctx.accounts.authority.key(),
+        operation,
+        eta,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint,
+    TokenAccount,
+    TokenInterface,
+    transfer_checked,
+    TransferChecked,
+};
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ InvestorFeePositionOwner, Policy, DistributionProgress, DistributionEpoch },
+    utils::pda::position_owner_signer_seeds,
+    events::DistributionEpochSwept,
+};
+
+#[derive(Accounts)]
+pub struct SweepExpiredEpoch<'info> {
+    /// Anyone can sweep an expired epoch (permissionless)
+    pub cranker: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Position owner PDA (treasury authority)
+    #[account(
+        seeds = [
+            VAULT_SEED,
+            vault.key().as_ref(),
+            INVESTOR_FEE_POS_OWNER_SEED
+        ],
+        bump = position_owner.bump
+    )]
+    pub position_owner: Box<Account<'info, InvestorFeePositionOwner>>,
+
+    /// Quote mint
+    #[account(constraint = quote_mint.key() == position_owner.quote_mint)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    /// Program-owned treasury for quote tokens
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, vault.key().as_ref(), quote_mint.key().as_ref()],
+        bump,
+        token::mint = quote_mint,
+        token::authority = position_owner
+    )]
+    pub treasury_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Creator's quote token ATA
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = policy.creator_wallet
+    )]
+    pub creator_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Distribution policy
+    #[account(seeds = [POLICY_SEED, vault.key().as_ref()], bump = policy.bump)]
+    pub policy: Box<Account<'info, Policy>>,
+
+    /// Distribution progress (lifetime creator totals)
+    #[account(
+        mut,
+        seeds = [PROGRESS_SEED, vault.key().as_ref()],
+        bump = progress.bump
+    )]
+    pub progress: Box<Account<'info, DistributionProgress>>,
+
+    /// Expired claim epoch
+    #[account(
+        mut,
+        seeds = [
+            DISTRIBUTION_EPOCH_SEED,
+            vault.key().as_ref(),
+            &epoch.epoch_index.to_le_bytes()
+        ],
+        bump = epoch.bump
+    )]
+    pub epoch: Box<Account<'info, DistributionEpoch>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Send whatever an expired epoch left unclaimed to the creator
+pub fn handle_sweep_expired_epoch(ctx: Context<SweepExpiredEpoch>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let epoch = &mut ctx.accounts.epoch;
+
+    require!(!epoch.swept, HonouraryError::DistributionEpochSwept);
+    require!(current_time >= epoch.expires_at, HonouraryError::DistributionEpochNotExpired);
+
+    let unclaimed = epoch.unclaimed_amount();
+    epoch.swept = true;
+
+    if unclaimed > 0 {
+        let vault_key = ctx.accounts.vault.key();
+        let bump_slice = [ctx.accounts.position_owner.bump];
+        let signer_seeds = position_owner_signer_seeds(&vault_key, &bump_slice);
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.treasury_ata.to_account_info(),
+                    mint: ctx.accounts.quote_mint.to_account_info(),
+                    to: ctx.accounts.creator_ata.to_account_info(),
+                    authority: ctx.accounts.position_owner.to_account_info(),
+                },
+                &[&signer_seeds[..]]
+            ),
+            unclaimed,
+            ctx.accounts.quote_mint.decimals
+        )?;
+    }
+
+    let progress = &mut ctx.accounts.progress;
+    progress.total_creator_distributed = progress.total_creator_distributed.saturating_add(unclaimed);
+
+    emit!(DistributionEpochSwept {
+        vault: ctx.accounts.vault.key(),
+        epoch_index: epoch.epoch_index,
+        amount: unclaimed,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::Policy,
+    events::VaultUnpaused,
+};
+
+#[derive(Accounts)]
+pub struct UnpauseVault<'info> {
+    /// Guardian, policy authority, or one of the admin signers when a signer set is configured
+    pub authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+}
+
+/// Resume cranks and liquidity operations where they stopped
+pub fn handle_unpause_vault(ctx: Context<UnpauseVault>) -> Result<()> {
+    ctx.accounts.policy.verify_guardian(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let policy = &mut ctx.accounts.policy;
+
+    require!(policy.paused, HonouraryError::VaultNotPaused);
+    policy.paused = false;
+
+    emit!(VaultUnpaused {
+        vault: ctx.accounts.vault.key(),
+        authority: ctx.accounts.authority.key(),
+        paused_seconds: current_time.saturating_sub(policy.paused_at),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::HonouraryError,
+    state::{ Policy, PolicyUpdateParams },
+    events::PolicyUpdateStaged,
+};
+
+#[derive(Accounts)]
+pub struct UpdatePolicy<'info> {
+    /// Policy authority (or one of the admin signers when a signer set is configured)
+    pub authority: Signer<'info>,
+
+    /// Vault identifier
+    /// CHECK: Used as PDA seed
+    pub vault: UncheckedAccount<'info>,
+
+    /// Distribution policy
+    #[account(
+        mut,
+        seeds = [POLICY_SEED, vault.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Box<Account<'info, Policy>>,
+}
+
+/// Stage a policy update. It can be applied with `apply_policy_update` once
+/// `update_delay_seconds` have passed and no distribution day is in progress.
+/// Staging again replaces the pending update and restarts the timelock.
+pub fn handle_update_policy(ctx: Context<UpdatePolicy>, params: PolicyUpdateParams) -> Result<()> {
+    ctx.accounts.policy.verify_admin(&ctx.accounts.authority, ctx.remaining_accounts)?;
+
+    // Validate staged values with the same rules as setup_policy
+    if let Some(investor_fee_share_bps) = params.investor_fee_share_bps {
+        require!(
+            investor_fee_share_bps <= BASIS_POINTS_DIVISOR as u16,
+            HonouraryError::InvalidPoolConfiguration
+        );
+    }
+
+    if let Some(min_payout_lamports) = params.min_payout_lamports {
+        require!(
+            min_payout_lamports >= MIN_PAYOUT_THRESHOLD,
+            HonouraryError::InvalidPoolConfiguration
+        );
+    }
+
+    if let Some(base_min_payout_lamports) = params.base_min_payout_lamports {
+        require!(
+            base_min_payout_lamports >= MIN_PAYOUT_THRESHOLD,
+            HonouraryError::InvalidPoolConfiguration
+        );
+    }
+
+    if let Some(reward_min_payout_lamports) = params.reward_min_payout_lamports {
+        require!(
+            reward_min_payout_lamports.iter().all(|&threshold| threshold >= MIN_PAYOUT_THRESHOLD),
+            HonouraryError::InvalidPoolConfiguration
+        );
+    }
+
+    if let Some(max_swap_slippage_bps) = params.max_swap_slippage_bps {
+        require!(
+            max_swap_slippage_bps <= BASIS_POINTS_DIVISOR as u16,
+            HonouraryError::InvalidPoolConfiguration
+        );
+    }
+
+    if let Some(compound_bps) = params.compound_bps {
+        require!(
+            compound_bps <= BASIS_POINTS_DIVISOR as u16,
+            HonouraryError::InvalidPoolConfiguration
+        );
+    }
+
+    if let Some(cranker_reward_bps) = params.cranker_reward_bps {
+        require!(
+            cranker_reward_bps <= BASIS_POINTS_DIVISOR as u16,
+            HonouraryError::InvalidPoolConfiguration
+        );
+    }
+
+    if let Some(total_investors) = params.total_investors {
+        require!(total_investors > 0, HonouraryError::InvalidPoolConfiguration);
+    }
+
+    if let Some(cadence) = &params.cadence {
+        cadence.validate()?;
+    }
+
+    if let Some(remainder_recipients) = &params.remainder_recipients {
+        Policy::validate_remainder_recipients(remainder_recipients)?;
+    }
+
+    if let Some(share_curve) = &params.share_curve {
+        Policy::validate_share_curve(share_curve)?;
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let policy = &mut ctx.accounts.policy;
+
+    let eta = current_time
+        .checked_add(policy.update_delay_seconds)
+        .ok_or(HonouraryError::MathOverflow)?;
+
+    policy.pending_update = Some(params);
+    policy.pending_update_eta = eta;
+
+    emit!(PolicyUpdateStaged {
+        vault: ctx.accounts.vault.key(),
+        authority: ctx.accounts.authority.key(),
+        eta,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
@@ -1,25 +1,46 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{ instruction::AccountMeta, program::invoke_signed };
-use anchor_spl::token_interface::{ TokenAccount, TokenInterface };
+use anchor_spl::token_interface::TokenAccount;
 use crate::cp_amm_types::{ Pool, Position, CreatePositionAccounts };
 use crate::error::HonouraryError;
+use crate::utils::math::{ amount_out_at_sqrt_price, apply_slippage };
+
+/// Accounts for creating an honorary position through cp-amm
+pub struct CreateHonoraryPositionAccounts<'a, 'info> {
+    pub pool: &'a Account<'info, Pool>,
+    pub position_owner_pda: &'a AccountInfo<'info>,
+    pub position_nft_mint: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+    pub cp_amm_program_account: &'a AccountInfo<'info>,
+    pub cp_amm_program: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+}
 
 /// Create honorary position through CP-AMM CPI
-pub fn create_honorary_position<'info>(
-    pool: &Account<'info, Pool>,
-    position_owner_pda: &AccountInfo<'info>,
-    position_nft_mint: &AccountInfo<'info>,
-    position: &AccountInfo<'info>,
-    position_nft_account: &AccountInfo<'info>,
-    pool_authority: &AccountInfo<'info>,
-    event_authority: &AccountInfo<'info>,
-    cp_amm_program_account: &AccountInfo<'info>,
-    cp_amm_program: &AccountInfo<'info>,
-    system_program: &AccountInfo<'info>,
-    token_program: &AccountInfo<'info>,
-    payer: &AccountInfo<'info>,
+pub fn create_honorary_position(
+    accounts: &CreateHonoraryPositionAccounts,
     signer_seeds: &[&[&[u8]]]
 ) -> Result<()> {
+    let CreateHonoraryPositionAccounts {
+        pool,
+        position_owner_pda,
+        position_nft_mint,
+        position,
+        position_nft_account,
+        pool_authority,
+        event_authority,
+        cp_amm_program_account,
+        cp_amm_program,
+        system_program,
+        token_program,
+        payer,
+    } = *accounts;
+
     msg!("Creating honorary position through CP-AMM CPI");
 
     // Build the CP-AMM create_position instruction
@@ -78,26 +99,52 @@ pub fn create_honorary_position<'info>(
     Ok(())
 }
 
-/// Claim fees from honorary position with quote-only validation
-pub fn claim_position_fees_quote_only<'info>(
-    position: &Account<'info, Position>,
-    pool: &Account<'info, Pool>,
-    position_owner_pda: &AccountInfo<'info>,
-    quote_mint: &AccountInfo<'info>,
-    base_mint: &AccountInfo<'info>,
-    quote_vault: &AccountInfo<'info>,
-    base_vault: &AccountInfo<'info>,
-    treasury_ata: &AccountInfo<'info>,
-    base_treasury_ata: &AccountInfo<'info>, // Should remain zero
-    quote_token_program: &Interface<'info, TokenInterface>,
-    base_token_program: &Interface<'info, TokenInterface>,
-    pool_authority: &AccountInfo<'info>,
-    position_nft_account: &AccountInfo<'info>,
-    event_authority: &AccountInfo<'info>,
-    cp_amm_program_account: &AccountInfo<'info>,
-    cp_amm_program: &AccountInfo<'info>,
+/// Accounts for claiming the honorary position's fees into the vault treasuries
+pub struct ClaimFeesAccounts<'a, 'info> {
+    pub position: &'a Account<'info, Position>,
+    pub pool: &'a Account<'info, Pool>,
+    pub position_owner_pda: &'a AccountInfo<'info>,
+    pub quote_mint: &'a AccountInfo<'info>,
+    pub base_mint: &'a AccountInfo<'info>,
+    pub quote_vault: &'a AccountInfo<'info>,
+    pub base_vault: &'a AccountInfo<'info>,
+    pub treasury_ata: &'a AccountInfo<'info>,
+    pub base_treasury_ata: &'a AccountInfo<'info>, // Should remain zero unless BothTokens
+    pub quote_token_program: &'a AccountInfo<'info>,
+    pub base_token_program: &'a AccountInfo<'info>,
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+    pub cp_amm_program_account: &'a AccountInfo<'info>,
+    pub cp_amm_program: &'a AccountInfo<'info>,
+}
+
+/// Claim fees from honorary position, returning `(quote_claimed, base_claimed)`.
+/// Unless `allow_base_fees` is set, any base token received aborts the claim.
+pub fn claim_position_fees(
+    accounts: &ClaimFeesAccounts,
+    allow_base_fees: bool,
     signer_seeds: &[&[&[u8]]]
-) -> Result<u64> {
+) -> Result<(u64, u64)> {
+    let ClaimFeesAccounts {
+        position,
+        pool,
+        position_owner_pda,
+        quote_mint,
+        base_mint,
+        quote_vault,
+        base_vault,
+        treasury_ata,
+        base_treasury_ata,
+        quote_token_program,
+        base_token_program,
+        pool_authority,
+        position_nft_account,
+        event_authority,
+        cp_amm_program_account,
+        cp_amm_program,
+    } = *accounts;
+
     // Record balance before claiming
     let treasury_before = {
         let account = TokenAccount::try_deserialize(&mut treasury_ata.try_borrow_data()?.as_ref())?;
@@ -209,8 +256,8 @@ pub fn claim_position_fees_quote_only<'info>(
             token_b_mint.clone(),
             position_nft_account.clone(),
             position_owner_pda.clone(),
-            token_a_program.to_account_info(),
-            token_b_program.to_account_info(),
+            token_a_program.clone(),
+            token_b_program.clone(),
             event_authority.clone(),
             cp_amm_program_account.clone(),
         ],
@@ -230,18 +277,437 @@ pub fn claim_position_fees_quote_only<'info>(
         account.amount
     };
 
-    // Ensure no base tokens were received
-    require_eq!(base_treasury_before, base_treasury_after, HonouraryError::BaseFeesDetected);
+    // Ensure no base tokens were received by quote-only vaults
+    if !allow_base_fees {
+        require_eq!(base_treasury_before, base_treasury_after, HonouraryError::BaseFeesDetected);
+    }
 
-    // Calculate quote tokens received
+    // Calculate tokens received
     let quote_claimed = treasury_after
         .checked_sub(treasury_before)
         .ok_or(HonouraryError::MathOverflow)?;
+    let base_claimed = base_treasury_after
+        .checked_sub(base_treasury_before)
+        .ok_or(HonouraryError::MathOverflow)?;
 
     msg!("=== DEBUG: After claiming fees ===");
     msg!("Treasury quote balance after: {}", treasury_after);
     msg!("Treasury base balance after: {}", base_treasury_after);
     msg!("Quote tokens claimed: {}", quote_claimed);
+    msg!("Base tokens claimed: {}", base_claimed);
+
+    Ok((quote_claimed, base_claimed))
+}
+
+/// Accounts for swapping between the vault treasuries through cp-amm
+pub struct SwapAccounts<'a, 'info> {
+    pub pool: &'a Account<'info, Pool>,
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub position_owner_pda: &'a AccountInfo<'info>,
+    pub quote_mint: &'a AccountInfo<'info>,
+    pub base_mint: &'a AccountInfo<'info>,
+    pub quote_vault: &'a AccountInfo<'info>,
+    pub base_vault: &'a AccountInfo<'info>,
+    pub treasury_ata: &'a AccountInfo<'info>,
+    pub base_treasury_ata: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+    pub cp_amm_program_account: &'a AccountInfo<'info>,
+    pub cp_amm_program: &'a AccountInfo<'info>,
+}
+
+/// Direction of a treasury swap
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SwapDirection {
+    BaseToQuote,
+    QuoteToBase,
+}
+
+/// Swap `amount_in` from one vault treasury into the other with a cp-amm `swap2`
+/// (exact in) CPI. The minimum output is the pool's spot price (`sqrt_price`) less
+/// `max_slippage_bps`, which must also cover the pool trading fee.
+/// Returns the output tokens received.
+pub fn swap_treasury(
+    accounts: &SwapAccounts,
+    direction: SwapDirection,
+    amount_in: u64,
+    max_slippage_bps: u16,
+    signer_seeds: &[&[&[u8]]]
+) -> Result<u64> {
+    let pool = accounts.pool;
+    let base_is_token_a = pool.token_a_mint == accounts.base_mint.key();
+
+    let (input_account, output_account) = match direction {
+        SwapDirection::BaseToQuote => (accounts.base_treasury_ata, accounts.treasury_ata),
+        SwapDirection::QuoteToBase => (accounts.treasury_ata, accounts.base_treasury_ata),
+    };
+    let a_to_b = base_is_token_a == (direction == SwapDirection::BaseToQuote);
+
+    let expected_out = amount_out_at_sqrt_price(amount_in, pool.sqrt_price, a_to_b)?;
+    let minimum_amount_out = apply_slippage(expected_out, max_slippage_bps)?;
+
+    let output_before = TokenAccount::try_deserialize(
+        &mut output_account.try_borrow_data()?.as_ref()
+    )?.amount;
+
+    let (token_a_vault, token_b_vault) = if base_is_token_a {
+        (accounts.base_vault, accounts.quote_vault)
+    } else {
+        (accounts.quote_vault, accounts.base_vault)
+    };
+
+    let (token_a_mint, token_b_mint) = if base_is_token_a {
+        (accounts.base_mint, accounts.quote_mint)
+    } else {
+        (accounts.quote_mint, accounts.base_mint)
+    };
+
+    // Call CP-AMM swap2 instruction with SwapParameters2 { amount_0, amount_1, swap_mode }
+    // Discriminator calculated from SHA256("global:swap2")[0..8]
+    let mut instruction_data = vec![65, 75, 63, 76, 235, 91, 91, 136]; // swap2 discriminator
+    instruction_data.extend_from_slice(&amount_in.to_le_bytes()); // amount_0: amount in
+    instruction_data.extend_from_slice(&minimum_amount_out.to_le_bytes()); // amount_1: minimum out
+    instruction_data.push(0); // swap_mode: ExactIn
+
+    invoke_signed(
+        &(anchor_lang::solana_program::instruction::Instruction {
+            program_id: accounts.cp_amm_program.key(),
+            accounts: [
+                AccountMeta::new_readonly(accounts.pool_authority.key(), false),
+                AccountMeta::new(pool.key(), false),
+                AccountMeta::new(input_account.key(), false), // input_token_account
+                AccountMeta::new(output_account.key(), false), // output_token_account
+                AccountMeta::new(token_a_vault.key(), false),
+                AccountMeta::new(token_b_vault.key(), false),
+                AccountMeta::new_readonly(token_a_mint.key(), false),
+                AccountMeta::new_readonly(token_b_mint.key(), false),
+                AccountMeta::new_readonly(accounts.position_owner_pda.key(), true), // payer - signer (PDA)
+                AccountMeta::new_readonly(accounts.token_program.key(), false), // token_a_program
+                AccountMeta::new_readonly(accounts.token_program.key(), false), // token_b_program
+                // No referral account: Anchor reads the program id as None
+                AccountMeta::new_readonly(accounts.cp_amm_program.key(), false),
+                // Anchor #[event_cpi] requires these trailing accounts
+                AccountMeta::new_readonly(accounts.event_authority.key(), false),
+                AccountMeta::new_readonly(accounts.cp_amm_program_account.key(), false),
+            ].to_vec(),
+            data: instruction_data,
+        }),
+        &[
+            accounts.pool_authority.clone(),
+            pool.to_account_info(),
+            input_account.clone(),
+            output_account.clone(),
+            token_a_vault.clone(),
+            token_b_vault.clone(),
+            token_a_mint.clone(),
+            token_b_mint.clone(),
+            accounts.position_owner_pda.clone(),
+            accounts.token_program.clone(),
+            accounts.cp_amm_program.clone(),
+            accounts.event_authority.clone(),
+            accounts.cp_amm_program_account.clone(),
+        ],
+        signer_seeds
+    )?;
+
+    let output_after = TokenAccount::try_deserialize(
+        &mut output_account.try_borrow_data()?.as_ref()
+    )?.amount;
+
+    let received = output_after
+        .checked_sub(output_before)
+        .ok_or(HonouraryError::MathOverflow)?;
+
+    msg!("Swapped {} treasury tokens into {} (minimum {})", amount_in, received, minimum_amount_out);
+
+    Ok(received)
+}
+
+/// Accounts for adding liquidity to the honorary position from the vault treasuries
+pub struct AddLiquidityAccounts<'a, 'info> {
+    pub pool: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub position_owner_pda: &'a AccountInfo<'info>,
+    pub token_a_account: &'a AccountInfo<'info>,
+    pub token_b_account: &'a AccountInfo<'info>,
+    pub token_a_vault: &'a AccountInfo<'info>,
+    pub token_b_vault: &'a AccountInfo<'info>,
+    pub token_a_mint: &'a AccountInfo<'info>,
+    pub token_b_mint: &'a AccountInfo<'info>,
+    pub token_a_program: &'a AccountInfo<'info>,
+    pub token_b_program: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+    pub cp_amm_program: &'a AccountInfo<'info>,
+}
+
+/// Add `liquidity_delta` to the honorary position with a cp-amm `add_liquidity` CPI.
+/// The position owner PDA signs, as it owns both the position NFT and the token
+/// accounts the amounts are drawn from; the thresholds cap what cp-amm may take.
+pub fn add_liquidity(
+    accounts: &AddLiquidityAccounts,
+    liquidity_delta: u128,
+    token_a_amount_threshold: u64,
+    token_b_amount_threshold: u64,
+    signer_seeds: &[&[&[u8]]]
+) -> Result<()> {
+    // Discriminator from CP-AMM IDL, followed by AddLiquidityParameters
+    let mut instruction_data = vec![181, 157, 89, 67, 143, 182, 52, 72];
+    instruction_data.extend_from_slice(&liquidity_delta.to_le_bytes());
+    instruction_data.extend_from_slice(&token_a_amount_threshold.to_le_bytes());
+    instruction_data.extend_from_slice(&token_b_amount_threshold.to_le_bytes());
 
-    Ok(quote_claimed)
+    invoke_signed(
+        &(anchor_lang::solana_program::instruction::Instruction {
+            program_id: accounts.cp_amm_program.key(),
+            accounts: vec![
+                AccountMeta::new(accounts.pool.key(), false),
+                AccountMeta::new(accounts.position.key(), false),
+                AccountMeta::new(accounts.token_a_account.key(), false),
+                AccountMeta::new(accounts.token_b_account.key(), false),
+                AccountMeta::new(accounts.token_a_vault.key(), false),
+                AccountMeta::new(accounts.token_b_vault.key(), false),
+                AccountMeta::new_readonly(accounts.token_a_mint.key(), false),
+                AccountMeta::new_readonly(accounts.token_b_mint.key(), false),
+                AccountMeta::new_readonly(accounts.position_nft_account.key(), false),
+                AccountMeta::new_readonly(accounts.position_owner_pda.key(), true), // PDA signs (owns NFT & treasury)
+                AccountMeta::new_readonly(accounts.token_a_program.key(), false),
+                AccountMeta::new_readonly(accounts.token_b_program.key(), false),
+                AccountMeta::new_readonly(accounts.event_authority.key(), false), // CP-AMM event authority
+                AccountMeta::new_readonly(accounts.cp_amm_program.key(), false), // CP-AMM program
+            ],
+            data: instruction_data,
+        }),
+        &[
+            accounts.pool.clone(),
+            accounts.position.clone(),
+            accounts.token_a_account.clone(),
+            accounts.token_b_account.clone(),
+            accounts.token_a_vault.clone(),
+            accounts.token_b_vault.clone(),
+            accounts.token_a_mint.clone(),
+            accounts.token_b_mint.clone(),
+            accounts.position_nft_account.clone(),
+            accounts.position_owner_pda.clone(),
+            accounts.token_a_program.clone(),
+            accounts.token_b_program.clone(),
+            accounts.event_authority.clone(),
+            accounts.cp_amm_program.clone(),
+        ],
+        signer_seeds
+    )?;
+
+    msg!("Added {} liquidity to honorary position", liquidity_delta);
+
+    Ok(())
+}
+
+/// Accounts for withdrawing liquidity from the honorary position
+pub struct RemoveLiquidityAccounts<'a, 'info> {
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub pool: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub position_owner_pda: &'a AccountInfo<'info>,
+    pub token_a_account: &'a AccountInfo<'info>,
+    pub token_b_account: &'a AccountInfo<'info>,
+    pub token_a_vault: &'a AccountInfo<'info>,
+    pub token_b_vault: &'a AccountInfo<'info>,
+    pub token_a_mint: &'a AccountInfo<'info>,
+    pub token_b_mint: &'a AccountInfo<'info>,
+    pub token_a_program: &'a AccountInfo<'info>,
+    pub token_b_program: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+    pub cp_amm_program_account: &'a AccountInfo<'info>,
+    pub cp_amm_program: &'a AccountInfo<'info>,
+}
+
+/// Withdraw `liquidity_delta` from the honorary position with a cp-amm
+/// `remove_liquidity` CPI. cp-amm pays the tokens straight into the given token
+/// accounts, which need not be owned by the position owner PDA; pending fees stay
+/// in the position for the crank.
+pub fn remove_liquidity(
+    accounts: &RemoveLiquidityAccounts,
+    liquidity_delta: u128,
+    token_a_amount_threshold: u64,
+    token_b_amount_threshold: u64,
+    signer_seeds: &[&[&[u8]]]
+) -> Result<()> {
+    // Discriminator calculated from SHA256("global:remove_liquidity")[0..8]
+    let mut instruction_data = vec![80, 85, 209, 72, 24, 206, 177, 108];
+    instruction_data.extend_from_slice(&liquidity_delta.to_le_bytes());
+    instruction_data.extend_from_slice(&token_a_amount_threshold.to_le_bytes());
+    instruction_data.extend_from_slice(&token_b_amount_threshold.to_le_bytes());
+
+    invoke_signed(
+        &(anchor_lang::solana_program::instruction::Instruction {
+            program_id: accounts.cp_amm_program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly(accounts.pool_authority.key(), false),
+                AccountMeta::new(accounts.pool.key(), false),
+                AccountMeta::new(accounts.position.key(), false),
+                AccountMeta::new(accounts.token_a_account.key(), false),
+                AccountMeta::new(accounts.token_b_account.key(), false),
+                AccountMeta::new(accounts.token_a_vault.key(), false),
+                AccountMeta::new(accounts.token_b_vault.key(), false),
+                AccountMeta::new_readonly(accounts.token_a_mint.key(), false),
+                AccountMeta::new_readonly(accounts.token_b_mint.key(), false),
+                AccountMeta::new_readonly(accounts.position_nft_account.key(), false),
+                AccountMeta::new_readonly(accounts.position_owner_pda.key(), true), // owner - signer (PDA)
+                AccountMeta::new_readonly(accounts.token_a_program.key(), false),
+                AccountMeta::new_readonly(accounts.token_b_program.key(), false),
+                AccountMeta::new_readonly(accounts.event_authority.key(), false),
+                AccountMeta::new_readonly(accounts.cp_amm_program_account.key(), false),
+            ],
+            data: instruction_data,
+        }),
+        &[
+            accounts.pool_authority.clone(),
+            accounts.pool.clone(),
+            accounts.position.clone(),
+            accounts.token_a_account.clone(),
+            accounts.token_b_account.clone(),
+            accounts.token_a_vault.clone(),
+            accounts.token_b_vault.clone(),
+            accounts.token_a_mint.clone(),
+            accounts.token_b_mint.clone(),
+            accounts.position_nft_account.clone(),
+            accounts.position_owner_pda.clone(),
+            accounts.token_a_program.clone(),
+            accounts.token_b_program.clone(),
+            accounts.event_authority.clone(),
+            accounts.cp_amm_program_account.clone(),
+        ],
+        signer_seeds
+    )?;
+
+    msg!("Removed {} liquidity from honorary position", liquidity_delta);
+
+    Ok(())
+}
+
+/// Accounts for closing the honorary position
+pub struct ClosePositionAccounts<'a, 'info> {
+    pub position_nft_mint: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub pool: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub rent_receiver: &'a AccountInfo<'info>,
+    pub position_owner_pda: &'a AccountInfo<'info>,
+    pub token_2022_program: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+    pub cp_amm_program_account: &'a AccountInfo<'info>,
+    pub cp_amm_program: &'a AccountInfo<'info>,
+}
+
+/// Close an empty honorary position with a cp-amm `close_position` CPI, burning
+/// the position NFT and returning the rent to `rent_receiver`
+pub fn close_position(accounts: &ClosePositionAccounts, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+    // Discriminator calculated from SHA256("global:close_position")[0..8]
+    let instruction_data = &[123, 134, 81, 0, 49, 68, 98, 98]; // close_position discriminator
+
+    invoke_signed(
+        &(anchor_lang::solana_program::instruction::Instruction {
+            program_id: accounts.cp_amm_program.key(),
+            accounts: vec![
+                AccountMeta::new(accounts.position_nft_mint.key(), false),
+                AccountMeta::new(accounts.position_nft_account.key(), false),
+                AccountMeta::new(accounts.pool.key(), false),
+                AccountMeta::new(accounts.position.key(), false),
+                AccountMeta::new_readonly(accounts.pool_authority.key(), false),
+                AccountMeta::new(accounts.rent_receiver.key(), false),
+                AccountMeta::new_readonly(accounts.position_owner_pda.key(), true), // owner - signer (PDA)
+                AccountMeta::new_readonly(accounts.token_2022_program.key(), false),
+                AccountMeta::new_readonly(accounts.event_authority.key(), false),
+                AccountMeta::new_readonly(accounts.cp_amm_program_account.key(), false),
+            ],
+            data: instruction_data.to_vec(),
+        }),
+        &[
+            accounts.position_nft_mint.clone(),
+            accounts.position_nft_account.clone(),
+            accounts.pool.clone(),
+            accounts.position.clone(),
+            accounts.pool_authority.clone(),
+            accounts.rent_receiver.clone(),
+            accounts.position_owner_pda.clone(),
+            accounts.token_2022_program.clone(),
+            accounts.event_authority.clone(),
+            accounts.cp_amm_program_account.clone(),
+        ],
+        signer_seeds
+    )?;
+
+    msg!("Closed honorary position {}", accounts.position.key());
+
+    Ok(())
+}
+
+/// Accounts for claiming a farm reward earned by the honorary position
+pub struct ClaimRewardAccounts<'a, 'info> {
+    pub pool_authority: &'a AccountInfo<'info>,
+    pub pool: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    pub reward_vault: &'a AccountInfo<'info>,
+    pub reward_mint: &'a AccountInfo<'info>,
+    pub reward_treasury: &'a AccountInfo<'info>,
+    pub position_nft_account: &'a AccountInfo<'info>,
+    pub position_owner_pda: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub event_authority: &'a AccountInfo<'info>,
+    pub cp_amm_program_account: &'a AccountInfo<'info>,
+    pub cp_amm_program: &'a AccountInfo<'info>,
+}
+
+/// Claim the position's pending reward for `reward_index` into the reward treasury
+/// with a cp-amm `claim_reward` CPI. With `skip_reward` set, cp-amm settles the
+/// pending reward without transferring it, which is the only way past a frozen
+/// reward vault.
+pub fn claim_reward(
+    accounts: &ClaimRewardAccounts,
+    reward_index: u8,
+    skip_reward: bool,
+    signer_seeds: &[&[&[u8]]]
+) -> Result<()> {
+    // Discriminator calculated from SHA256("global:claim_reward")[0..8]
+    let mut instruction_data = vec![149, 95, 181, 242, 94, 90, 158, 162];
+    instruction_data.push(reward_index);
+    instruction_data.push(skip_reward as u8);
+
+    invoke_signed(
+        &(anchor_lang::solana_program::instruction::Instruction {
+            program_id: accounts.cp_amm_program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly(accounts.pool_authority.key(), false),
+                AccountMeta::new(accounts.pool.key(), false),
+                AccountMeta::new(accounts.position.key(), false),
+                AccountMeta::new(accounts.reward_vault.key(), false),
+                AccountMeta::new_readonly(accounts.reward_mint.key(), false),
+                AccountMeta::new(accounts.reward_treasury.key(), false), // user_token_account
+                AccountMeta::new_readonly(accounts.position_nft_account.key(), false),
+                AccountMeta::new_readonly(accounts.position_owner_pda.key(), true), // owner - signer (PDA)
+                AccountMeta::new_readonly(accounts.token_program.key(), false),
+                AccountMeta::new_readonly(accounts.event_authority.key(), false),
+                AccountMeta::new_readonly(accounts.cp_amm_program_account.key(), false),
+            ],
+            data: instruction_data,
+        }),
+        &[
+            accounts.pool_authority.clone(),
+            accounts.pool.clone(),
+            accounts.position.clone(),
+            accounts.reward_vault.clone(),
+            accounts.reward_mint.clone(),
+            accounts.reward_treasury.clone(),
+            accounts.position_nft_account.clone(),
+            accounts.position_owner_pda.clone(),
+            accounts.token_program.clone(),
+            accounts.event_authority.clone(),
+            accounts.cp_amm_program_account.clone(),
+        ],
+        signer_seeds
+    )?;
+
+    Ok(())
 }
@@ -0,0 +1,253 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{ token::Token, token_2022::Token2022, token_interface::TokenAccount };
+use crate::cp_amm_types::{ Position, Vesting, CP_AMM_PROGRAM_ID };
+use crate::error::HonouraryError;
+use crate::integrations::streamflow::{ average_locked_amount, deserialize_stream, locked_amount_at };
+use crate::state::{ InvestorSchedule, LockedAmountSource };
+use crate::utils::math::{ time_weighted_average, StepUnlock };
+
+/// cp-amm pool activation type measured in slots (otherwise timestamps)
+const ACTIVATION_TYPE_SLOT: u8 = 0;
+
+/// The instant a snapshot reads locked amounts at, and what lock accounts are
+/// checked against
+pub struct LockSnapshotContext {
+    /// The vault being snapshotted (investor schedules must belong to it)
+    pub vault: Pubkey,
+    /// The vault's pool (cp-amm vestings must lock one of its positions)
+    pub pool: Pubkey,
+    /// The vault's investor (base) token vested by Streamflow streams
+    pub investor_mint: Pubkey,
+    /// Snapshot timestamp
+    pub timestamp: i64,
+    /// Snapshot slot
+    pub slot: u64,
+    /// Activation type of the vault's pool, the unit of cp-amm vesting points
+    pub pool_activation_type: u8,
+    /// Start (timestamp, slot) of the period to average locked amounts over,
+    /// or `None` to read them at the snapshot instant
+    pub average_from: Option<(i64, u64)>,
+}
+
+/// Program that owns the lock accounts of a source
+pub fn lock_account_owner(source: LockedAmountSource) -> Pubkey {
+    match source {
+        LockedAmountSource::Streamflow => streamflow_sdk::ID,
+        LockedAmountSource::CpAmmVesting => CP_AMM_PROGRAM_ID,
+        LockedAmountSource::Schedule => crate::ID,
+    }
+}
+
+/// Accounts passed per investor for a source's lock account: cp-amm vestings
+/// are followed by their position and the token account holding its NFT
+pub fn lock_accounts_per_investor(source: LockedAmountSource) -> usize {
+    match source {
+        LockedAmountSource::CpAmmVesting => 3,
+        LockedAmountSource::Streamflow | LockedAmountSource::Schedule => 1,
+    }
+}
+
+/// Read the amount locked in an investor's lock account at the snapshot
+/// instant (or its average over the snapshot's period), checking the account
+/// is of the policy's source and vests for the registered recipient.
+///
+/// cp-amm vestings do not record who they vest for: `lock_extras` carries the
+/// vesting's position, which must be in the vault's pool, and the token account
+/// holding the position NFT, which must be the recipient's.
+pub fn read_locked_amount(
+    source: LockedAmountSource,
+    lock_account: &AccountInfo,
+    lock_extras: &[AccountInfo],
+    recipient: &Pubkey,
+    snapshot: &LockSnapshotContext
+) -> Result<u64> {
+    let owner_error = match source {
+        LockedAmountSource::Streamflow => HonouraryError::StreamOwnerMismatch,
+        _ => HonouraryError::InvalidLockAccount,
+    };
+    require_keys_eq!(*lock_account.owner, lock_account_owner(source), owner_error);
+    require!(!lock_account.data_is_empty(), HonouraryError::InvalidLockAccount);
+
+    match source {
+        LockedAmountSource::Streamflow => {
+            let stream = deserialize_stream(lock_account)?;
+            require_keys_eq!(stream.mint, snapshot.investor_mint, HonouraryError::StreamMintMismatch);
+            require_keys_eq!(stream.recipient, *recipient, HonouraryError::StreamRecipientMismatch);
+
+            Ok(match snapshot.average_from {
+                Some((from_ts, _)) => average_locked_amount(&stream, from_ts, snapshot.timestamp),
+                None => locked_amount_at(&stream, snapshot.timestamp),
+            })
+        }
+        LockedAmountSource::CpAmmVesting => {
+            let vesting = Vesting::try_deserialize(&mut &lock_account.try_borrow_data()?[..])
+                .map_err(|_| error!(HonouraryError::InvalidLockAccount))?;
+            verify_vesting_position(&vesting, lock_extras, recipient, snapshot)?;
+            let to_point = |timestamp: i64, slot: u64| {
+                if snapshot.pool_activation_type == ACTIVATION_TYPE_SLOT { slot } else { timestamp.max(0) as u64 }
+            };
+            let current_point = to_point(snapshot.timestamp, snapshot.slot);
+
+            let locked = vesting.get_locked_liquidity(current_point).ok_or(HonouraryError::MathOverflow)?;
+            let locked = u64::try_from(locked).map_err(|_| HonouraryError::MathOverflow)?;
+
+            let Some((from_ts, from_slot)) = snapshot.average_from else {
+                return Ok(locked);
+            };
+            let from_point = to_point(from_ts, from_slot);
+            let curve = vesting_unlock_curve(&vesting)?;
+            let integral = curve.unlocked_integral(from_point, current_point);
+
+            Ok(match time_weighted_average(integral, from_point, current_point) {
+                Some(average_unlocked) => curve.cliff_amount
+                    .saturating_add(curve.stream_cap)
+                    .saturating_sub(average_unlocked),
+                None => locked,
+            })
+        }
+        LockedAmountSource::Schedule => {
+            let schedule = InvestorSchedule::try_deserialize(&mut &lock_account.try_borrow_data()?[..])
+                .map_err(|_| error!(HonouraryError::InvalidLockAccount))?;
+            require_keys_eq!(schedule.vault, snapshot.vault, HonouraryError::InvalidLockAccount);
+            require_keys_eq!(schedule.recipient, *recipient, HonouraryError::StreamRecipientMismatch);
+
+            Ok(match snapshot.average_from {
+                Some((from_ts, _)) => schedule.average_locked(from_ts, snapshot.timestamp),
+                None => schedule.locked_at(snapshot.timestamp),
+            })
+        }
+    }
+}
+
+/// Check that a cp-amm vesting locks a position of the vault's pool whose NFT
+/// is held by the registered recipient
+fn verify_vesting_position(
+    vesting: &Vesting,
+    lock_extras: &[AccountInfo],
+    recipient: &Pubkey,
+    snapshot: &LockSnapshotContext
+) -> Result<()> {
+    let [position_account, nft_account] = lock_extras else {
+        return err!(HonouraryError::AccountCountMismatch);
+    };
+
+    require_keys_eq!(position_account.key(), vesting.position, HonouraryError::InvalidLockAccount);
+    require_keys_eq!(*position_account.owner, CP_AMM_PROGRAM_ID, HonouraryError::InvalidLockAccount);
+    let position = Position::try_deserialize(&mut &position_account.try_borrow_data()?[..])
+        .map_err(|_| error!(HonouraryError::InvalidLockAccount))?;
+    require_keys_eq!(position.pool, snapshot.pool, HonouraryError::InvalidLockAccount);
+
+    require!(
+        *nft_account.owner == Token::id() || *nft_account.owner == Token2022::id(),
+        HonouraryError::InvalidLockAccount
+    );
+    let nft = TokenAccount::try_deserialize(&mut &nft_account.try_borrow_data()?[..])
+        .map_err(|_| error!(HonouraryError::InvalidLockAccount))?;
+    require!(
+        nft.mint == position.nft_mint && nft.amount == 1,
+        HonouraryError::InvalidLockAccount
+    );
+    require_keys_eq!(nft.owner, *recipient, HonouraryError::StreamRecipientMismatch);
+
+    Ok(())
+}
+
+/// A cp-amm vesting's liquidity schedule as cliff and period releases
+fn vesting_unlock_curve(vesting: &Vesting) -> Result<StepUnlock> {
+    let to_u64 = |liquidity: u128| u64::try_from(liquidity).map_err(|_| error!(HonouraryError::MathOverflow));
+    let total = vesting.get_total_lock_amount().ok_or(HonouraryError::MathOverflow)?;
+
+    Ok(StepUnlock {
+        cliff_point: vesting.cliff_point,
+        cliff_amount: to_u64(vesting.cliff_unlock_liquidity)?,
+        stream_start: vesting.cliff_point,
+        stream_initial: 0,
+        period: vesting.period_frequency,
+        amount_per_period: to_u64(vesting.liquidity_per_period)?,
+        stream_cap: to_u64(total - vesting.cliff_unlock_liquidity)?,
+        frozen_at: u64::MAX,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token;
+
+    fn leak_account(owner: Pubkey, data: Vec<u8>) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(Pubkey::new_unique())),
+            false,
+            false,
+            Box::leak(Box::new(1_000_000)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            false,
+            0
+        )
+    }
+
+    fn nft_account(mint: Pubkey, owner: Pubkey) -> AccountInfo<'static> {
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account {
+            mint,
+            owner,
+            amount: 1,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }.pack_into_slice(&mut data);
+        leak_account(spl_token::ID, data)
+    }
+
+    #[test]
+    fn test_vesting_position_must_be_in_pool_and_held_by_recipient() {
+        let pool = Pubkey::new_unique();
+        let nft_mint = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let snapshot = LockSnapshotContext {
+            vault: Pubkey::new_unique(),
+            pool,
+            investor_mint: Pubkey::new_unique(),
+            timestamp: 0,
+            slot: 0,
+            pool_activation_type: ACTIVATION_TYPE_SLOT,
+            average_from: None,
+        };
+
+        let position_in = |pool: Pubkey| {
+            let mut data = vec![0u8; 8];
+            Position { pool, nft_mint, ..Default::default() }.try_serialize(&mut data).unwrap();
+            leak_account(CP_AMM_PROGRAM_ID, data)
+        };
+        let position = position_in(pool);
+        let vesting = Vesting {
+            position: position.key(),
+            cliff_point: 0,
+            period_frequency: 0,
+            cliff_unlock_liquidity: 0,
+            liquidity_per_period: 0,
+            total_released_liquidity: 0,
+            number_of_period: 0,
+            padding: [0; 14],
+            padding2: [0; 4],
+        };
+
+        let held = nft_account(nft_mint, recipient);
+        verify_vesting_position(&vesting, &[position.clone(), held.clone()], &recipient, &snapshot).unwrap();
+
+        // The NFT held by someone else does not vest for the registered recipient
+        let foreign = nft_account(nft_mint, Pubkey::new_unique());
+        assert!(verify_vesting_position(&vesting, &[position.clone(), foreign], &recipient, &snapshot).is_err());
+
+        // A position of another pool, even one the vesting points at, is rejected
+        let other_pool_position = position_in(Pubkey::new_unique());
+        let other_vesting = Vesting { position: other_pool_position.key(), ..vesting };
+        assert!(
+            verify_vesting_position(&other_vesting, &[other_pool_position, held.clone()], &recipient, &snapshot).is_err()
+        );
+
+        // The passed position must be the vesting's own
+        assert!(verify_vesting_position(&vesting, &[position_in(pool), held], &recipient, &snapshot).is_err());
+    }
+}
@@ -1,5 +1,7 @@
 pub mod cp_amm;
+pub mod locked_source;
 pub mod streamflow;
 
 pub use cp_amm::*;
+pub use locked_source::*;
 pub use streamflow::*;
\ No newline at end of file
@@ -1,42 +1,47 @@
 use anchor_lang::prelude::*;
 use crate::error::HonouraryError;
+use crate::utils::math::{ time_weighted_average, StepUnlock };
 use streamflow_sdk::state::Contract as StreamflowContract;
 
 #[derive(Clone)]
 pub struct InvestorData<'info> {
     pub stream_account: &'info AccountInfo<'info>,
-    pub investor_ata: &'info AccountInfo<'info>,
+    /// Accounts the lock account's source reads alongside it (see `lock_accounts_per_investor`)
+    pub lock_extras: &'info [AccountInfo<'info>],
+    /// Absent when the page carries lock accounts only (MerkleClaim vaults)
+    pub investor_ata: Option<&'info AccountInfo<'info>>,
 }
 
-/// Parse investor accounts from remaining accounts
+/// Parse investor accounts from remaining accounts: per investor, the
+/// `lock_accounts` accounts of its lock (the lock account first), followed by
+/// the investor ATA unless `with_payout_accounts` is false
 pub fn parse_investor_accounts<'info>(
     remaining_accounts: &'info [AccountInfo<'info>],
     page_start: u32,
-    page_size: u32
+    page_size: u32,
+    lock_accounts: usize,
+    with_payout_accounts: bool
 ) -> Result<Vec<InvestorData<'info>>> {
-    let start_idx = (page_start * 2) as usize; // 2 accounts per investor
-    let end_idx = ((page_start + page_size) * 2) as usize;
+    let accounts_per_investor = lock_accounts + if with_payout_accounts { 1 } else { 0 };
+    let start_idx = (page_start as usize) * accounts_per_investor;
+    let end_idx = ((page_start + page_size) as usize) * accounts_per_investor;
 
     require!(end_idx <= remaining_accounts.len(), HonouraryError::InvalidPagination);
 
-    let mut investors = Vec::new();
-
-    for i in (start_idx..end_idx).step_by(2) {
-        investors.push(InvestorData {
-            stream_account: &remaining_accounts[i],
-            investor_ata: &remaining_accounts[i + 1],
-        });
-    }
+    let investors = remaining_accounts[start_idx..end_idx]
+        .chunks(accounts_per_investor)
+        .map(|accounts| InvestorData {
+            stream_account: &accounts[0],
+            lock_extras: &accounts[1..lock_accounts],
+            investor_ata: accounts.get(lock_accounts),
+        })
+        .collect();
 
     Ok(investors)
 }
 
-/// Read locked amount from a Streamflow stream account using the official SDK
-pub fn read_locked_amount_from_stream(
-    stream_account: &AccountInfo,
-    current_timestamp: i64
-) -> Result<u64> {
-    // Use the official Streamflow SDK to parse the contract
+/// Deserialize a Streamflow stream account using the official SDK
+pub fn deserialize_stream(stream_account: &AccountInfo) -> Result<StreamflowContract> {
     let stream_contract = StreamflowContract::deserialize(
         &mut &stream_account.data.borrow()[..]
     ).map_err(|e| {
@@ -44,59 +49,277 @@ pub fn read_locked_amount_from_stream(
         HonouraryError::InsufficientStreamflowData
     })?;
 
-    // Calculate locked amount using SDK methods
-    // locked = total_deposited - available_to_claim
-    let current_timestamp_u64 = current_timestamp as u64;
-    let total_deposited = stream_contract.ix.net_amount_deposited;
+    Ok(stream_contract)
+}
 
-    // Note: The Streamflow SDK's available_to_claim() has a bug where fee_percentage=0.0
-    // causes it to return 0 due to calculate_fee_from_amount returning 0 for 0%.
-    // We pass 100.0 to mean "100% available to recipient (0% fee to Streamflow)".
-    // This uses floating-point math, so we request additional compute units in crank_distribution.
-    let available = stream_contract.available_to_claim(current_timestamp_u64, 100.0);
-    let locked_amount = total_deposited.saturating_sub(available);
+/// Locked amount of an already-parsed stream at the given timestamp.
+///
+/// A canceled or closed stream has paid out or returned all of its tokens, so
+/// nothing is locked in it any more.
+pub fn locked_amount_at(stream_contract: &StreamflowContract, current_timestamp: i64) -> u64 {
+    if stream_contract.closed || stream_contract.canceled_at > 0 {
+        return 0;
+    }
 
-    Ok(locked_amount)
+    let unlocked = unlocked_amount_at(stream_contract, current_timestamp.max(0) as u64);
+    stream_contract.ix.net_amount_deposited.saturating_sub(unlocked)
 }
 
-/// Calculate total locked across all investor streams
-pub fn calculate_total_locked_amounts(
-    investors: &[InvestorData],
-    current_timestamp: i64
-) -> Result<(Vec<u64>, u64)> {
-    let mut individual_locked = Vec::new();
-    let mut total_locked = 0u64;
-
-    for investor in investors {
-        let locked = read_locked_amount_from_stream(investor.stream_account, current_timestamp)?;
-        individual_locked.push(locked);
-        total_locked = total_locked.checked_add(locked).ok_or(HonouraryError::MathOverflow)?;
+/// Amount of a stream unlocked to its recipient by `now`, withdrawn or not.
+///
+/// Integer reimplementation of the SDK's `available_to_claim(now, 100.0) +
+/// amount_withdrawn`: the SDK scales both terms by a floating point fee factor
+/// that is exactly 1 at 100%, which costs compute for nothing. Unlike the SDK
+/// the result never exceeds the deposit, never falls below what was already
+/// withdrawn, and a zero period streams nothing instead of panicking.
+pub fn unlocked_amount_at(stream_contract: &StreamflowContract, now: u64) -> u64 {
+    let deposited = stream_contract.ix.net_amount_deposited;
+    let start = stream_contract.start_time();
+
+    let unlocked = if start > now {
+        0
+    } else if now >= stream_contract.end_time && stream_contract.current_pause_start == 0 {
+        deposited as u128
+    } else {
+        streamed_amount(stream_contract, start, now) + cliff_amount(stream_contract, now)
+    };
+
+    unlocked.max(stream_contract.amount_withdrawn as u128).min(deposited as u128) as u64
+}
+
+/// Average amount locked in a stream over `[from, to)`, or the amount locked
+/// at `to` when the span is empty
+pub fn average_locked_amount(stream_contract: &StreamflowContract, from: i64, to: i64) -> u64 {
+    if stream_contract.closed || stream_contract.canceled_at > 0 {
+        return 0;
     }
 
-    Ok((individual_locked, total_locked))
+    let (from, to) = (from.max(0) as u64, to.max(0) as u64);
+    let integral = unlock_curve(stream_contract).unlocked_integral(from, to);
+    match time_weighted_average(integral, from, to) {
+        Some(average_unlocked) => stream_contract.ix.net_amount_deposited.saturating_sub(average_unlocked),
+        None => locked_amount_at(stream_contract, to as i64),
+    }
 }
 
-/// Streamflow program IDs (mainnet and devnet)
-pub const STREAMFLOW_PROGRAM_ID: &str = "strmRqUCoQUgGUan5YhzUZa6KqdzwX5L6FpUxfmKg5m";
-pub const STREAMFLOW_DEVNET_PROGRAM_ID: &str = "HqDGZjaVRXJ9MGRQEw7qDc2rAr6iH1n1kAQdCZaCMfMZ";
-
-/// Validate Streamflow account ownership and structure
-pub fn validate_streamflow_accounts(
-    investors: &[InvestorData],
-    streamflow_program_id: &Pubkey
-) -> Result<()> {
-    for investor in investors {
-        // Verify stream account is owned by Streamflow program
-        require_keys_eq!(
-            *investor.stream_account.owner,
-            *streamflow_program_id,
-            HonouraryError::InsufficientStreamflowData
-        );
-
-        // Additional validation: check account has data
-        let data = investor.stream_account.try_borrow_data()?;
-        require!(!data.is_empty(), HonouraryError::InsufficientStreamflowData);
+/// The stream's unlock schedule as cliff and period releases. Only the current
+/// rate and pause state are on chain, so the schedule before the last rate
+/// change or pause is approximated with them.
+pub fn unlock_curve(stream_contract: &StreamflowContract) -> StepUnlock {
+    let start = stream_contract.start_time();
+    let pause_start = stream_contract.current_pause_start;
+    let paused_before = |point: u64| pause_start != 0 && pause_start < point;
+
+    StepUnlock {
+        cliff_point: if paused_before(stream_contract.ix.cliff) { u64::MAX } else { start },
+        cliff_amount: stream_contract.ix.cliff_amount,
+        stream_start: stream_contract
+            .effective_start_time()
+            .saturating_add(stream_contract.pause_cumulative),
+        stream_initial: if paused_before(start) {
+            0
+        } else {
+            stream_contract.funds_unlocked_at_last_rate_change
+        },
+        period: stream_contract.ix.period,
+        amount_per_period: stream_contract.ix.amount_per_period,
+        stream_cap: stream_contract.ix.net_amount_deposited.saturating_sub(stream_contract.ix.cliff_amount),
+        frozen_at: if pause_start != 0 { pause_start } else { u64::MAX },
     }
+}
+
+/// Amount released by the stream's periods since `start` (the SDK's `vested_available`)
+fn streamed_amount(stream_contract: &StreamflowContract, start: u64, now: u64) -> u128 {
+    let pause_start = stream_contract.current_pause_start;
+
+    // Paused since before the start: nothing has streamed
+    if pause_start != 0 && pause_start < start {
+        return 0;
+    }
+
+    let paused_for = if pause_start != 0 {
+        stream_contract.pause_cumulative.saturating_add(now.saturating_sub(pause_start))
+    } else {
+        stream_contract.pause_cumulative
+    };
+    let streaming_for = now
+        .saturating_sub(stream_contract.effective_start_time())
+        .saturating_sub(paused_for);
+    let periods_passed = streaming_for.checked_div(stream_contract.ix.period).unwrap_or(0);
+
+    (periods_passed as u128) * (stream_contract.ix.amount_per_period as u128) +
+        (stream_contract.funds_unlocked_at_last_rate_change as u128)
+}
+
+/// Amount released at the cliff (the SDK's `cliff_available`)
+fn cliff_amount(stream_contract: &StreamflowContract, now: u64) -> u128 {
+    let pause_start = stream_contract.current_pause_start;
+    if (pause_start != 0 && pause_start < stream_contract.ix.cliff) || now < stream_contract.ix.cliff {
+        return 0;
+    }
+    stream_contract.ix.cliff_amount as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use streamflow_sdk::state::CreateParams;
 
-    Ok(())
+    fn stream(start_time: u64, deposited: u64, period: u64, amount_per_period: u64, cliff: u64, cliff_amount: u64) -> StreamflowContract {
+        let streamed_periods = (deposited - cliff_amount).div_ceil(amount_per_period.max(1));
+        let start = if cliff > 0 { cliff } else { start_time };
+
+        StreamflowContract {
+            magic: 0,
+            version: 0,
+            created_at: start_time,
+            amount_withdrawn: 0,
+            canceled_at: 0,
+            end_time: start + streamed_periods * period,
+            last_withdrawn_at: 0,
+            sender: Pubkey::default(),
+            sender_tokens: Pubkey::default(),
+            recipient: Pubkey::default(),
+            recipient_tokens: Pubkey::default(),
+            mint: Pubkey::default(),
+            escrow_tokens: Pubkey::default(),
+            streamflow_treasury: Pubkey::default(),
+            streamflow_treasury_tokens: Pubkey::default(),
+            streamflow_fee_total: 0,
+            streamflow_fee_withdrawn: 0,
+            streamflow_fee_percent: 0.0,
+            partner: Pubkey::default(),
+            partner_tokens: Pubkey::default(),
+            partner_fee_total: 0,
+            partner_fee_withdrawn: 0,
+            partner_fee_percent: 0.0,
+            ix: CreateParams {
+                start_time,
+                net_amount_deposited: deposited,
+                period,
+                amount_per_period,
+                cliff,
+                cliff_amount,
+                cancelable_by_sender: true,
+                cancelable_by_recipient: false,
+                automatic_withdrawal: false,
+                transferable_by_sender: false,
+                transferable_by_recipient: false,
+                can_topup: false,
+                stream_name: [0; 64],
+                withdraw_frequency: 0,
+                ghost: 0,
+                pausable: true,
+                can_update_rate: true,
+            },
+            ix_padding: Vec::new(),
+            closed: false,
+            current_pause_start: 0,
+            pause_cumulative: 0,
+            last_rate_change_time: 0,
+            funds_unlocked_at_last_rate_change: 0,
+        }
+    }
+
+    /// xorshift64: deterministic schedules without a dev-dependency
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        /// Uniform-ish value in `low..=high`
+        fn between(&mut self, low: u64, high: u64) -> u64 {
+            low + self.next() % (high - low + 1)
+        }
+    }
+
+    #[test]
+    fn test_unlocked_amount_matches_sdk_for_random_schedules() {
+        let mut rng = Rng(0x5eed_f00d_1234_5678);
+
+        for _ in 0..5_000 {
+            let start_time = rng.between(1, 2_000_000_000);
+            let deposited = rng.between(1, 1_000_000_000_000);
+            let period = rng.between(1, 30 * 86_400);
+            let amount_per_period = rng.between(1, deposited);
+            let cliff = if rng.next() % 2 == 0 { 0 } else { start_time + rng.between(0, 365 * 86_400) };
+            let cliff_amount = if cliff > 0 { rng.between(0, deposited) } else { 0 };
+            let mut contract = stream(start_time, deposited, period, amount_per_period, cliff, cliff_amount);
+            let start = contract.start_time();
+            let now = rng.between(start_time.saturating_sub(86_400), contract.end_time + 86_400);
+
+            if now >= start {
+                match rng.next() % 4 {
+                    // Paused some time after the start, possibly with earlier pauses
+                    0 => {
+                        contract.current_pause_start = rng.between(start, now).max(1);
+                        contract.pause_cumulative = rng.between(0, contract.current_pause_start - start);
+                    }
+                    // Finished earlier pauses
+                    1 => contract.pause_cumulative = rng.between(0, now - start),
+                    // Rate changed after the start
+                    2 => {
+                        contract.last_rate_change_time = rng.between(start, now);
+                        contract.funds_unlocked_at_last_rate_change = rng.between(0, deposited);
+                    }
+                    _ => {}
+                }
+            }
+
+            // Withdraw at most what was available, as the Streamflow program does
+            let available = contract.available_to_claim(now, 100.0);
+            contract.amount_withdrawn = rng.between(0, available.min(deposited));
+
+            let sdk_unlocked = contract.available_to_claim(now, 100.0) + contract.amount_withdrawn;
+            let unlocked = unlocked_amount_at(&contract, now);
+            assert_eq!(unlocked, sdk_unlocked.min(deposited), "schedule {:?} at {}", contract.ix, now);
+            assert_eq!(locked_amount_at(&contract, now as i64), deposited - unlocked);
+        }
+    }
+
+    #[test]
+    fn test_average_locked_amount_follows_the_steps() {
+        // 1_000 over 10 periods of 100 seconds from t=1_000, 200 of it at the cliff
+        let contract = stream(0, 1_000, 100, 80, 1_000, 200);
+        let curve = unlock_curve(&contract);
+        for point in [0, 999, 1_000, 1_099, 1_100, 1_450, 1_999, 2_000, 5_000] {
+            assert_eq!(curve.unlocked_at(point), unlocked_amount_at(&contract, point), "at {}", point);
+        }
+
+        // One whole period: flat at 1_000 - 200 - 80
+        assert_eq!(average_locked_amount(&contract, 1_100, 1_200), 720);
+        // Spanning the cliff: half fully locked, half at 800
+        assert_eq!(average_locked_amount(&contract, 900, 1_100), 900);
+        // An empty window reads the point
+        assert_eq!(average_locked_amount(&contract, 1_450, 1_450), locked_amount_at(&contract, 1_450));
+    }
+
+    #[test]
+    fn test_withdrawn_and_canceled_streams_are_not_locked() {
+        // 1_000 over 10 periods of 100 seconds from t=1_000
+        let mut contract = stream(1_000, 1_000, 100, 100, 0, 0);
+        assert_eq!(locked_amount_at(&contract, 999), 1_000);
+        assert_eq!(locked_amount_at(&contract, 1_450), 600);
+
+        // Withdrawn tokens are unlocked ones, not locked again
+        contract.amount_withdrawn = 400;
+        assert_eq!(locked_amount_at(&contract, 1_450), 600);
+        contract.amount_withdrawn = 1_000;
+        assert_eq!(locked_amount_at(&contract, 2_000), 0);
+
+        // Unvested tokens of a canceled stream went back to the sender
+        contract.amount_withdrawn = 0;
+        contract.canceled_at = 1_450;
+        assert_eq!(locked_amount_at(&contract, 1_450), 0);
+
+        // A zero period streams nothing rather than panicking
+        let mut contract = stream(1_000, 1_000, 100, 100, 0, 0);
+        contract.ix.period = 0;
+        assert_eq!(locked_amount_at(&contract, 1_450), 1_000);
+    }
 }
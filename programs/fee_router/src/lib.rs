@@ -26,8 +26,11 @@ pub mod fee_router {
     use super::*;
 
     /// Initialize an honorary position that accrues only quote token fees
-    pub fn initialize_honorary_position(ctx: Context<InitializeHonoraryPosition>) -> Result<()> {
-        instructions::handle_initialize_honorary_position(ctx)
+    pub fn initialize_honorary_position(
+        ctx: Context<InitializeHonoraryPosition>,
+        fee_collection: FeeCollection
+    ) -> Result<()> {
+        instructions::handle_initialize_honorary_position(ctx, fee_collection)
     }
 
     /// Setup distribution policy and parameters
@@ -35,9 +38,18 @@ pub mod fee_router {
         instructions::handle_setup_policy(ctx, params)
     }
 
+    /// Snapshot investor locked amounts for the day (paginated, runs before payouts)
+    pub fn snapshot_locked<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SnapshotLocked<'info>>,
+        page_start: u32,
+        page_size: u32
+    ) -> Result<()> {
+        instructions::handle_snapshot_locked(ctx, page_start, page_size)
+    }
+
     /// Crank the 24-hour distribution system (paginated)
     pub fn crank_distribution<'info>(
-        ctx: Context<'_, '_, '_, 'info, CrankDistribution<'info>>,
+        ctx: Context<'_, '_, 'info, 'info, CrankDistribution<'info>>,
         page_start: u32,
         page_size: u32
     ) -> Result<()> {
@@ -48,6 +60,21 @@ pub mod fee_router {
         )
     }
 
+    /// Distribute a cp-amm farm reward earned by the honorary position (paginated)
+    pub fn crank_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CrankRewards<'info>>,
+        reward_index: u8,
+        page_start: u32,
+        page_size: u32
+    ) -> Result<()> {
+        instructions::handle_crank_rewards(ctx, reward_index, page_start, page_size)
+    }
+
+    /// Open an additional quote-only honorary position in another pool
+    pub fn add_honorary_position(ctx: Context<AddHonoraryPosition>) -> Result<()> {
+        instructions::handle_add_honorary_position(ctx)
+    }
+
     /// Add liquidity to the honorary position
     pub fn add_honorary_liquidity(
         ctx: Context<AddHonoraryLiquidity>,
@@ -62,4 +89,143 @@ pub mod fee_router {
             token_b_amount_threshold
         )
     }
+
+    /// Register an investor's lock account and payout account in the registry
+    pub fn register_investor(
+        ctx: Context<RegisterInvestor>,
+        params: RegisterInvestorParams
+    ) -> Result<()> {
+        instructions::handle_register_investor(ctx, params)
+    }
+
+    /// Remove an investor from the registry
+    pub fn remove_investor(ctx: Context<RemoveInvestor>, investor_index: u32) -> Result<()> {
+        instructions::handle_remove_investor(ctx, investor_index)
+    }
+
+    /// Stage new terms for an investor's native vesting schedule behind the policy timelock
+    pub fn set_investor_schedule(
+        ctx: Context<SetInvestorSchedule>,
+        params: InvestorScheduleParams
+    ) -> Result<()> {
+        instructions::handle_set_investor_schedule(ctx, params)
+    }
+
+    /// Apply an investor schedule's staged terms once their timelock has elapsed
+    pub fn apply_investor_schedule(ctx: Context<ApplyInvestorSchedule>) -> Result<()> {
+        instructions::handle_apply_investor_schedule(ctx)
+    }
+
+    /// Stage a policy update behind the policy timelock
+    pub fn update_policy(ctx: Context<UpdatePolicy>, params: PolicyUpdateParams) -> Result<()> {
+        instructions::handle_update_policy(ctx, params)
+    }
+
+    /// Apply the staged policy update once its timelock has elapsed
+    pub fn apply_policy_update(ctx: Context<ApplyPolicyUpdate>) -> Result<()> {
+        instructions::handle_apply_policy_update(ctx)
+    }
+
+    /// Cancel the staged policy update
+    pub fn cancel_policy_update(ctx: Context<CancelPolicyUpdate>) -> Result<()> {
+        instructions::handle_cancel_policy_update(ctx)
+    }
+
+    /// Propose a new policy authority (two-step transfer)
+    pub fn propose_policy_authority(
+        ctx: Context<ProposePolicyAuthority>,
+        new_authority: Pubkey
+    ) -> Result<()> {
+        instructions::handle_propose_policy_authority(ctx, new_authority)
+    }
+
+    /// Accept a proposed policy authority transfer
+    pub fn accept_policy_authority(ctx: Context<AcceptPolicyAuthority>) -> Result<()> {
+        instructions::handle_accept_policy_authority(ctx)
+    }
+
+    /// Configure the M-of-N admin signer set
+    pub fn set_policy_signers(
+        ctx: Context<SetPolicySigners>,
+        signers: Vec<Pubkey>,
+        threshold: u8
+    ) -> Result<()> {
+        instructions::handle_set_policy_signers(ctx, signers, threshold)
+    }
+
+    /// Commit a Merkle claim epoch for the reserved investor pool
+    pub fn commit_distribution_epoch(
+        ctx: Context<CommitDistributionEpoch>,
+        params: CommitDistributionEpochParams
+    ) -> Result<()> {
+        instructions::handle_commit_distribution_epoch(ctx, params)
+    }
+
+    /// Claim an investor's leaf from a distribution epoch
+    pub fn claim_distribution(
+        ctx: Context<ClaimDistribution>,
+        leaf_index: u32,
+        amount: u64,
+        proof: Vec<[u8; 32]>
+    ) -> Result<()> {
+        instructions::handle_claim_distribution(ctx, leaf_index, amount, proof)
+    }
+
+    /// Withdraw payouts escrowed for an investor whose payout account was unusable
+    pub fn claim_pending_payout(ctx: Context<ClaimPendingPayout>) -> Result<()> {
+        instructions::handle_claim_pending_payout(ctx)
+    }
+
+    /// Withdraw farm rewards escrowed for an investor whose reward account was unusable
+    pub fn claim_pending_reward_payout(ctx: Context<ClaimPendingRewardPayout>) -> Result<()> {
+        instructions::handle_claim_pending_reward_payout(ctx)
+    }
+
+    /// Sweep the unclaimed balance of an expired epoch to the creator
+    pub fn sweep_expired_epoch(ctx: Context<SweepExpiredEpoch>) -> Result<()> {
+        instructions::handle_sweep_expired_epoch(ctx)
+    }
+
+    /// Stage a timelocked liquidity withdrawal or migration of the honorary position
+    pub fn stage_position_operation(
+        ctx: Context<StagePositionOperation>,
+        operation: PositionOperation
+    ) -> Result<()> {
+        instructions::handle_stage_position_operation(ctx, operation)
+    }
+
+    /// Discard the staged position operation
+    pub fn cancel_position_operation(ctx: Context<CancelPositionOperation>) -> Result<()> {
+        instructions::handle_cancel_position_operation(ctx)
+    }
+
+    /// Withdraw principal from the honorary position once its timelock has elapsed
+    pub fn remove_honorary_liquidity(
+        ctx: Context<RemoveHonoraryLiquidity>,
+        liquidity_delta: u128,
+        token_a_amount_threshold: u64,
+        token_b_amount_threshold: u64
+    ) -> Result<()> {
+        instructions::handle_remove_honorary_liquidity(
+            ctx,
+            liquidity_delta,
+            token_a_amount_threshold,
+            token_b_amount_threshold
+        )
+    }
+
+    /// Move the emptied honorary position to another pool once its timelock has elapsed
+    pub fn migrate_honorary_position(ctx: Context<MigrateHonoraryPosition>) -> Result<()> {
+        instructions::handle_migrate_honorary_position(ctx)
+    }
+
+    /// Halt cranks and liquidity operations (guardian or admins)
+    pub fn pause_vault(ctx: Context<PauseVault>) -> Result<()> {
+        instructions::handle_pause_vault(ctx)
+    }
+
+    /// Resume a paused vault (guardian or admins)
+    pub fn unpause_vault(ctx: Context<UnpauseVault>) -> Result<()> {
+        instructions::handle_unpause_vault(ctx)
+    }
 }
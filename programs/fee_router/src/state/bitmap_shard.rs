@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use crate::constants::INVESTORS_PER_BITMAP_SHARD;
+
+const SHARD_BITMAP_BYTES: usize = (INVESTORS_PER_BITMAP_SHARD / 8) as usize;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PaidBitmapShard {
+    /// The vault this shard belongs to
+    pub vault: Pubkey,
+
+    /// Shard index (covers global investor indices
+    /// [shard_index * INVESTORS_PER_BITMAP_SHARD, (shard_index + 1) * INVESTORS_PER_BITMAP_SHARD))
+    pub shard_index: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Distribution day the bits below belong to
+    /// Bits from an older day are stale and read as unset
+    pub day: u64,
+
+    /// Investors paid during `day` (bit i = investor shard_index * 8192 + i)
+    pub paid_bitmap: [u8; SHARD_BITMAP_BYTES],
+
+    /// Investors included in the locked-amount snapshot during `day`
+    pub snapshot_bitmap: [u8; SHARD_BITMAP_BYTES],
+}
+
+impl PaidBitmapShard {
+    pub const SEEDS_PREFIX: &'static [u8] = crate::constants::PAID_BITMAP_SHARD_SEED;
+
+    /// Shard that tracks the given global investor index
+    pub fn shard_for(investor_index: u32) -> u32 {
+        investor_index / INVESTORS_PER_BITMAP_SHARD
+    }
+
+    /// Lazily reset the shard when it is first touched on a new distribution day
+    pub fn sync_day(&mut self, day: u64) {
+        if self.day != day {
+            self.day = day;
+            self.paid_bitmap = [0u8; SHARD_BITMAP_BYTES];
+            self.snapshot_bitmap = [0u8; SHARD_BITMAP_BYTES];
+        }
+    }
+
+    /// Check if an investor has already been paid today
+    pub fn is_investor_paid(&self, investor_index: u32) -> bool {
+        self.is_set(&self.paid_bitmap, investor_index)
+    }
+
+    /// Mark an investor as paid
+    pub fn mark_investor_paid(&mut self, investor_index: u32) -> Result<()> {
+        let (byte_idx, mask) = self.locate(investor_index)?;
+        self.paid_bitmap[byte_idx] |= mask;
+        Ok(())
+    }
+
+    /// Check if an investor's locked amount is already in today's snapshot
+    pub fn is_investor_snapshotted(&self, investor_index: u32) -> bool {
+        self.is_set(&self.snapshot_bitmap, investor_index)
+    }
+
+    /// Mark an investor as included in today's snapshot
+    pub fn mark_investor_snapshotted(&mut self, investor_index: u32) -> Result<()> {
+        let (byte_idx, mask) = self.locate(investor_index)?;
+        self.snapshot_bitmap[byte_idx] |= mask;
+        Ok(())
+    }
+
+    fn is_set(&self, bitmap: &[u8], investor_index: u32) -> bool {
+        match self.locate(investor_index) {
+            Ok((byte_idx, mask)) => (bitmap[byte_idx] & mask) != 0,
+            Err(_) => false, // Not tracked by this shard, treat as not set
+        }
+    }
+
+    fn locate(&self, investor_index: u32) -> Result<(usize, u8)> {
+        require!(
+            Self::shard_for(investor_index) == self.shard_index,
+            crate::error::HonouraryError::InvalidPagination
+        );
+
+        let local_index = investor_index % INVESTORS_PER_BITMAP_SHARD;
+        Ok(((local_index / 8) as usize, 1 << (local_index % 8)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shard(shard_index: u32, day: u64) -> PaidBitmapShard {
+        PaidBitmapShard {
+            vault: Pubkey::default(),
+            shard_index,
+            bump: 0,
+            day,
+            paid_bitmap: [0u8; SHARD_BITMAP_BYTES],
+            snapshot_bitmap: [0u8; SHARD_BITMAP_BYTES],
+        }
+    }
+
+    #[test]
+    fn test_shard_tracks_investors_beyond_2048() {
+        let mut shard = shard(1, 1);
+        let investor = INVESTORS_PER_BITMAP_SHARD + 5000;
+
+        assert!(!shard.is_investor_paid(investor));
+        shard.mark_investor_paid(investor).unwrap();
+        assert!(shard.is_investor_paid(investor));
+        assert!(!shard.is_investor_snapshotted(investor));
+
+        // Indices owned by another shard are rejected
+        assert!(shard.mark_investor_paid(5000).is_err());
+    }
+
+    #[test]
+    fn test_shard_resets_lazily_on_new_day() {
+        let mut shard = shard(0, 1);
+        shard.mark_investor_paid(7).unwrap();
+        shard.mark_investor_snapshotted(7).unwrap();
+
+        // Same day keeps the bits
+        shard.sync_day(1);
+        assert!(shard.is_investor_paid(7));
+
+        // A later day clears them
+        shard.sync_day(2);
+        assert!(!shard.is_investor_paid(7));
+        assert!(!shard.is_investor_snapshotted(7));
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct DistributionEpoch {
+    /// The vault this epoch belongs to
+    pub vault: Pubkey,
+
+    /// Sequential epoch index (see DistributionProgress::next_epoch_index)
+    pub epoch_index: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Root of the (index, investor, amount) leaf tree
+    pub merkle_root: [u8; 32],
+
+    /// Number of leaves in the tree
+    pub leaf_count: u32,
+
+    /// Quote tokens reserved for this epoch
+    pub total_amount: u64,
+
+    /// Quote tokens claimed so far
+    pub claimed_amount: u64,
+
+    /// Distribution day whose sealed snapshot the leaves were built from
+    pub snapshot_day: u64,
+
+    /// Total locked amount of that snapshot, for investors to check their leaf against
+    pub snapshot_total_locked: u64,
+
+    /// Timestamp the epoch was committed
+    pub created_at: i64,
+
+    /// Claims open at this timestamp, once the policy's update delay has passed
+    pub claimable_at: i64,
+
+    /// Claims are rejected and the remainder can be swept from this timestamp
+    pub expires_at: i64,
+
+    /// Whether unclaimed funds have been swept to the creator
+    pub swept: bool,
+
+    /// Claimed leaves (bit i = leaf index i)
+    pub claimed_bitmap: Vec<u8>,
+}
+
+impl DistributionEpoch {
+    pub const SEEDS_PREFIX: &'static [u8] = crate::constants::DISTRIBUTION_EPOCH_SEED;
+
+    /// Account space (without discriminator) for an epoch with `leaf_count` leaves
+    pub fn space(leaf_count: u32) -> usize {
+        32 + 8 + 1 + 32 + 4 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 4 + Self::bitmap_len(leaf_count)
+    }
+
+    pub fn bitmap_len(leaf_count: u32) -> usize {
+        (leaf_count as usize).div_ceil(8)
+    }
+
+    /// Unclaimed balance still reserved by this epoch
+    pub fn unclaimed_amount(&self) -> u64 {
+        self.total_amount.saturating_sub(self.claimed_amount)
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.claimed_bitmap
+            .get((index / 8) as usize)
+            .is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+    }
+
+    pub fn mark_claimed(&mut self, index: u32) -> Result<()> {
+        require!(index < self.leaf_count, crate::error::HonouraryError::InvalidMerkleProof);
+        self.claimed_bitmap[(index / 8) as usize] |= 1 << (index % 8);
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// An additional honorary position of a vault, in another pool sharing the
+/// vault's quote mint. Index 0 is the primary position kept on
+/// `InvestorFeePositionOwner`; additional positions are numbered from 1, are
+/// owned by the same position owner PDA and always collect quote-only fees.
+#[account]
+#[derive(InitSpace)]
+pub struct HonoraryPosition {
+    /// The vault this position belongs to
+    pub vault: Pubkey,
+
+    /// Position index (1..=InvestorFeePositionOwner::additional_position_count)
+    pub index: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// The DAMM v2 pool this position belongs to
+    pub pool: Pubkey,
+
+    /// The NFT mint for this position
+    pub position_mint: Pubkey,
+
+    /// The position account created in cp-amm
+    pub position_account: Pubkey,
+
+    /// The pool's other (non-quote) mint
+    pub base_mint: Pubkey,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// Total quote fees claimed from this position
+    pub total_fees_claimed: u64,
+
+    /// Distribution day of the last claim
+    pub last_claimed_day: u64,
+}
+
+impl HonoraryPosition {
+    pub const SEEDS_PREFIX: &'static [u8] = crate::constants::HONORARY_POSITION_SEED;
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+/// Lifetime payout history of one investor, kept by the push-mode crank so the
+/// amounts an investor received can be read without indexing token transfers.
+///
+/// The ledger is push-only: MerkleClaim days never create or update it, and their
+/// claims are recorded by each epoch's claimed bitmap and `DistributionClaimed`
+/// events instead. A vault that switches modes only has its push payouts here.
+#[account]
+#[derive(InitSpace)]
+pub struct InvestorLedger {
+    /// The vault this ledger belongs to
+    pub vault: Pubkey,
+
+    /// Stream recipient the ledger tracks
+    pub investor: Pubkey,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Quote tokens the investor received: direct payouts and claimed escrow
+    pub total_quote_paid: u64,
+
+    /// Base tokens the investor received: direct payouts and claimed escrow
+    pub total_base_paid: u64,
+
+    /// Quote tokens escrowed for the investor and not yet claimed
+    pub pending_quote: u64,
+
+    /// Base tokens escrowed for the investor and not yet claimed
+    pub pending_base: u64,
+
+    /// Distribution day (DistributionProgress::current_day) the crank last paid the investor in
+    pub last_paid_day: u64,
+
+    /// Timestamp of the last payout
+    pub last_paid_at: i64,
+
+    /// Pro-rata weight (locked amount) used for the last payout
+    pub last_locked_amount: u64,
+
+    /// Number of days the investor was paid in
+    pub days_paid: u64,
+}
+
+impl InvestorLedger {
+    pub const SEEDS_PREFIX: &'static [u8] = crate::constants::INVESTOR_LEDGER_SEED;
+
+    /// Record one day's payout: what reached the investor's accounts and what
+    /// was escrowed for them. A day with nothing paid or escrowed is not counted.
+    pub fn record_payout(
+        &mut self,
+        day: u64,
+        locked_amount: u64,
+        paid: (u64, u64),
+        escrowed: (u64, u64),
+        current_time: i64
+    ) {
+        if paid == (0, 0) && escrowed == (0, 0) {
+            return;
+        }
+
+        self.total_quote_paid = self.total_quote_paid.saturating_add(paid.0);
+        self.total_base_paid = self.total_base_paid.saturating_add(paid.1);
+        self.pending_quote = self.pending_quote.saturating_add(escrowed.0);
+        self.pending_base = self.pending_base.saturating_add(escrowed.1);
+        self.last_paid_day = day;
+        self.last_paid_at = current_time;
+        self.last_locked_amount = locked_amount;
+        self.days_paid = self.days_paid.saturating_add(1);
+    }
+
+    /// Record an escrow withdrawn by `claim_pending_payout`
+    pub fn record_claim(&mut self, quote_amount: u64, base_amount: u64) {
+        self.pending_quote = self.pending_quote.saturating_sub(quote_amount);
+        self.pending_base = self.pending_base.saturating_sub(base_amount);
+        self.total_quote_paid = self.total_quote_paid.saturating_add(quote_amount);
+        self.total_base_paid = self.total_base_paid.saturating_add(base_amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_ledger() -> InvestorLedger {
+        InvestorLedger {
+            vault: Pubkey::default(),
+            investor: Pubkey::default(),
+            bump: 0,
+            total_quote_paid: 0,
+            total_base_paid: 0,
+            pending_quote: 0,
+            pending_base: 0,
+            last_paid_day: 0,
+            last_paid_at: 0,
+            last_locked_amount: 0,
+            days_paid: 0,
+        }
+    }
+
+    #[test]
+    fn test_escrow_counts_as_paid_once_claimed() {
+        let mut ledger = empty_ledger();
+
+        ledger.record_payout(1, 5_000, (100, 10), (0, 0), 1_000);
+        ledger.record_payout(2, 4_000, (0, 0), (80, 8), 2_000);
+        assert_eq!((ledger.total_quote_paid, ledger.total_base_paid), (100, 10));
+        assert_eq!((ledger.pending_quote, ledger.pending_base), (80, 8));
+        assert_eq!((ledger.last_paid_day, ledger.last_locked_amount, ledger.days_paid), (2, 4_000, 2));
+
+        ledger.record_claim(80, 8);
+        assert_eq!((ledger.total_quote_paid, ledger.total_base_paid), (180, 18));
+        assert_eq!((ledger.pending_quote, ledger.pending_base), (0, 0));
+    }
+
+    #[test]
+    fn test_zero_payout_day_is_not_recorded() {
+        let mut ledger = empty_ledger();
+
+        ledger.record_payout(1, 5_000, (100, 0), (0, 0), 1_000);
+        ledger.record_payout(2, 0, (0, 0), (0, 0), 2_000);
+        assert_eq!(ledger.total_quote_paid, 100);
+        assert_eq!((ledger.last_paid_day, ledger.last_paid_at, ledger.days_paid), (1, 1_000, 1));
+        assert_eq!(ledger.last_locked_amount, 5_000);
+    }
+}
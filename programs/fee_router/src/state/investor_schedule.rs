@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+
+/// Vesting terms staged by `set_investor_schedule`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct ScheduleTerms {
+    pub total_amount: u64,
+    pub cliff_ts: i64,
+    pub cliff_amount: u64,
+    pub end_ts: i64,
+}
+
+/// Native vesting schedule of one investor, for vaults whose policy reads
+/// locked amounts from `LockedAmountSource::Schedule`. Nothing unlocks before
+/// the cliff, `cliff_amount` unlocks at it and the rest unlocks linearly until
+/// `end_ts`. New terms are staged and take effect once the policy's update
+/// delay has passed; a schedule whose first terms are still staged locks nothing.
+#[account]
+#[derive(InitSpace)]
+pub struct InvestorSchedule {
+    /// The vault this schedule belongs to
+    pub vault: Pubkey,
+
+    /// Investor wallet the schedule vests for
+    pub recipient: Pubkey,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Total amount vested by the schedule
+    pub total_amount: u64,
+
+    /// Timestamp of the cliff
+    pub cliff_ts: i64,
+
+    /// Amount unlocked at the cliff
+    pub cliff_amount: u64,
+
+    /// Timestamp the whole amount is unlocked by
+    pub end_ts: i64,
+
+    /// Schedule creation timestamp
+    pub created_at: i64,
+
+    /// Schedule last updated timestamp
+    pub updated_at: i64,
+
+    /// Terms staged by `set_investor_schedule`, applied by `apply_investor_schedule`
+    pub pending_terms: Option<ScheduleTerms>,
+
+    /// Timestamp the staged terms can be applied at
+    pub pending_terms_eta: i64,
+}
+
+impl InvestorSchedule {
+    pub const SEEDS_PREFIX: &'static [u8] = crate::constants::INVESTOR_SCHEDULE_SEED;
+
+    /// Check the schedule parameters: the cliff amount fits in the total and
+    /// the schedule does not end before its cliff
+    pub fn validate(total_amount: u64, cliff_ts: i64, cliff_amount: u64, end_ts: i64) -> Result<()> {
+        require!(
+            cliff_amount <= total_amount && end_ts >= cliff_ts,
+            crate::error::HonouraryError::InvalidInvestorSchedule
+        );
+        Ok(())
+    }
+
+    /// Apply the staged terms once their timelock has elapsed
+    pub fn apply_staged_terms(&mut self, current_time: i64) -> Result<ScheduleTerms> {
+        let terms = self.pending_terms.ok_or(crate::error::HonouraryError::InvestorScheduleNotStaged)?;
+        require!(
+            current_time >= self.pending_terms_eta,
+            crate::error::HonouraryError::PolicyTimelockNotElapsed
+        );
+
+        self.total_amount = terms.total_amount;
+        self.cliff_ts = terms.cliff_ts;
+        self.cliff_amount = terms.cliff_amount;
+        self.end_ts = terms.end_ts;
+        self.updated_at = current_time;
+        self.pending_terms = None;
+        self.pending_terms_eta = 0;
+        Ok(terms)
+    }
+
+    /// Amount unlocked at `timestamp`
+    pub fn unlocked_at(&self, timestamp: i64) -> u64 {
+        if timestamp < self.cliff_ts {
+            return 0;
+        }
+        if timestamp >= self.end_ts {
+            return self.total_amount;
+        }
+
+        // Spans are taken in i128: a pre-1970 cliff can put them beyond i64
+        let linear_amount = (self.total_amount - self.cliff_amount) as u128;
+        let elapsed = (timestamp as i128 - self.cliff_ts as i128) as u128;
+        let duration = (self.end_ts as i128 - self.cliff_ts as i128) as u128;
+
+        // elapsed < duration, so the linear part stays below linear_amount
+        self.cliff_amount + ((linear_amount * elapsed) / duration) as u64
+    }
+
+    /// Amount still locked at `timestamp`
+    pub fn locked_at(&self, timestamp: i64) -> u64 {
+        self.total_amount.saturating_sub(self.unlocked_at(timestamp))
+    }
+
+    /// Average amount locked over `[from, to)`, or the amount locked at `to`
+    /// when the span is empty
+    pub fn average_locked(&self, from: i64, to: i64) -> u64 {
+        let integral = self.unlocked_integral_to(to).saturating_sub(self.unlocked_integral_to(from));
+        match crate::utils::math::time_weighted_average(integral, from.max(0) as u64, to.max(0) as u64) {
+            Some(average_unlocked) => self.total_amount.saturating_sub(average_unlocked),
+            None => self.locked_at(to),
+        }
+    }
+
+    /// ∫ unlocked from the epoch to `timestamp`, integrating the linear part exactly
+    fn unlocked_integral_to(&self, timestamp: i64) -> u128 {
+        if timestamp <= self.cliff_ts {
+            return 0;
+        }
+
+        let linear_end = timestamp.min(self.end_ts);
+        let elapsed = (linear_end as i128 - self.cliff_ts as i128) as u128;
+        let linear_amount = (self.total_amount - self.cliff_amount) as u128;
+        let ramp = if self.end_ts > self.cliff_ts {
+            linear_amount.saturating_mul(elapsed).saturating_mul(elapsed) /
+                (2 * (self.end_ts as i128 - self.cliff_ts as i128) as u128)
+        } else {
+            0
+        };
+
+        (self.cliff_amount as u128)
+            .saturating_mul(elapsed)
+            .saturating_add(ramp)
+            .saturating_add((self.total_amount as u128).saturating_mul((timestamp as i128 - linear_end as i128) as u128))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(total_amount: u64, cliff_ts: i64, cliff_amount: u64, end_ts: i64) -> InvestorSchedule {
+        InvestorSchedule {
+            vault: Pubkey::default(),
+            recipient: Pubkey::default(),
+            bump: 0,
+            total_amount,
+            cliff_ts,
+            cliff_amount,
+            end_ts,
+            created_at: 0,
+            updated_at: 0,
+            pending_terms: None,
+            pending_terms_eta: 0,
+        }
+    }
+
+    #[test]
+    fn test_cliff_then_linear_unlock() {
+        let schedule = schedule(1_000_000, 100, 250_000, 1_100);
+
+        assert_eq!(schedule.locked_at(0), 1_000_000);
+        assert_eq!(schedule.locked_at(99), 1_000_000);
+        assert_eq!(schedule.locked_at(100), 750_000);
+        // Halfway through the linear part
+        assert_eq!(schedule.locked_at(600), 375_000);
+        assert_eq!(schedule.locked_at(1_099), 750);
+        assert_eq!(schedule.locked_at(1_100), 0);
+        assert_eq!(schedule.locked_at(i64::MAX), 0);
+    }
+
+    #[test]
+    fn test_schedule_without_linear_part_unlocks_at_cliff() {
+        let schedule = schedule(500, 10, 0, 10);
+
+        assert_eq!(schedule.locked_at(9), 500);
+        assert_eq!(schedule.locked_at(10), 0);
+
+        assert!(InvestorSchedule::validate(500, 10, 0, 10).is_ok());
+        assert!(InvestorSchedule::validate(500, 10, 501, 20).is_err());
+        assert!(InvestorSchedule::validate(500, 10, 0, 9).is_err());
+    }
+
+    #[test]
+    fn test_average_locked_over_a_window() {
+        let schedule = schedule(1_000_000, 100, 250_000, 1_100);
+
+        // Fully locked, fully unlocked, and an empty window reads the end point
+        assert_eq!(schedule.average_locked(0, 100), 1_000_000);
+        assert_eq!(schedule.average_locked(1_100, 2_000), 0);
+        assert_eq!(schedule.average_locked(600, 600), 375_000);
+
+        // Half the window before the cliff, half on the ramp down to 675_000
+        assert_eq!(schedule.average_locked(0, 200), (1_000_000 + (750_000 + 675_000) / 2) / 2);
+
+        // A linear stretch averages to its midpoint
+        assert_eq!(schedule.average_locked(100, 1_100), 375_000);
+    }
+
+    #[test]
+    fn test_staged_terms_wait_for_timelock() {
+        let mut schedule = schedule(0, 0, 0, 0);
+        assert!(schedule.apply_staged_terms(0).is_err());
+
+        let terms = ScheduleTerms { total_amount: 1_000, cliff_ts: 100, cliff_amount: 0, end_ts: 200 };
+        schedule.pending_terms = Some(terms);
+        schedule.pending_terms_eta = 50;
+
+        // Nothing is locked until the terms are applied
+        assert!(schedule.apply_staged_terms(49).is_err());
+        assert_eq!(schedule.locked_at(0), 0);
+
+        assert_eq!(schedule.apply_staged_terms(50).unwrap(), terms);
+        assert_eq!(schedule.locked_at(0), 1_000);
+        assert!(schedule.pending_terms.is_none());
+        assert!(schedule.apply_staged_terms(60).is_err());
+    }
+
+    #[test]
+    fn test_extreme_timestamps_do_not_overflow() {
+        let schedule = schedule(1_000, i64::MIN, 0, i64::MAX);
+
+        assert_eq!(schedule.locked_at(i64::MIN), 1_000);
+        assert_eq!(schedule.locked_at(0), 500);
+        assert_eq!(schedule.locked_at(i64::MAX), 0);
+        assert!(schedule.average_locked(i64::MIN, i64::MAX) <= 1_000);
+    }
+}
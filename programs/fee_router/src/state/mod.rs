@@ -1,7 +1,27 @@
+pub mod bitmap_shard;
+pub mod epoch;
+pub mod honorary_position;
+pub mod investor_ledger;
+pub mod investor_schedule;
 pub mod position_owner;
 pub mod policy;
+pub mod pending_payout;
+pub mod pending_reward_payout;
 pub mod progress;
+pub mod registered_lock;
+pub mod registry;
+pub mod reward_progress;
 
+pub use bitmap_shard::*;
+pub use epoch::*;
+pub use honorary_position::*;
+pub use investor_ledger::*;
+pub use investor_schedule::*;
 pub use position_owner::*;
 pub use policy::*;
-pub use progress::*;
\ No newline at end of file
+pub use pending_payout::*;
+pub use pending_reward_payout::*;
+pub use progress::*;
+pub use registered_lock::*;
+pub use registry::*;
+pub use reward_progress::*;
\ No newline at end of file
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+/// Payouts owed to one investor whose payout account could not receive them
+/// (closed, frozen or not a token account of the payout mint). The tokens stay
+/// in the vault treasuries until the investor withdraws them with
+/// `claim_pending_payout`.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingPayout {
+    /// The vault this escrow belongs to
+    pub vault: Pubkey,
+
+    /// Stream recipient the payouts are owed to
+    pub investor: Pubkey,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Base mint of the escrowed base payouts (default until one is escrowed)
+    pub base_mint: Pubkey,
+
+    /// Quote tokens owed
+    pub quote_amount: u64,
+
+    /// Base tokens owed
+    pub base_amount: u64,
+
+    /// Total lifetime quote tokens escrowed
+    pub total_quote_escrowed: u64,
+
+    /// Total lifetime base tokens escrowed
+    pub total_base_escrowed: u64,
+
+    /// Timestamp of the last escrowed payout
+    pub last_escrowed_at: i64,
+}
+
+impl PendingPayout {
+    pub const SEEDS_PREFIX: &'static [u8] = crate::constants::PENDING_PAYOUT_SEED;
+
+    /// Record payouts that could not be delivered
+    pub fn credit(&mut self, quote_amount: u64, base_amount: u64, current_time: i64) -> Result<()> {
+        self.quote_amount = self.quote_amount
+            .checked_add(quote_amount)
+            .ok_or(crate::error::HonouraryError::MathOverflow)?;
+        self.base_amount = self.base_amount
+            .checked_add(base_amount)
+            .ok_or(crate::error::HonouraryError::MathOverflow)?;
+        self.total_quote_escrowed = self.total_quote_escrowed.saturating_add(quote_amount);
+        self.total_base_escrowed = self.total_base_escrowed.saturating_add(base_amount);
+        self.last_escrowed_at = current_time;
+        Ok(())
+    }
+
+    /// Empty the escrow, returning the (quote, base) amounts owed
+    pub fn take(&mut self) -> (u64, u64) {
+        let owed = (self.quote_amount, self.base_amount);
+        self.quote_amount = 0;
+        self.base_amount = 0;
+        owed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credit_accumulates_until_taken() {
+        let mut pending_payout = PendingPayout {
+            vault: Pubkey::default(),
+            investor: Pubkey::default(),
+            bump: 0,
+            base_mint: Pubkey::default(),
+            quote_amount: 0,
+            base_amount: 0,
+            total_quote_escrowed: 0,
+            total_base_escrowed: 0,
+            last_escrowed_at: 0,
+        };
+
+        pending_payout.credit(1_500, 0, 10).unwrap();
+        pending_payout.credit(2_500, 700, 20).unwrap();
+        assert_eq!(pending_payout.take(), (4_000, 700));
+        assert_eq!(pending_payout.take(), (0, 0));
+
+        // Lifetime totals survive the withdrawal
+        assert_eq!(pending_payout.total_quote_escrowed, 4_000);
+        assert_eq!(pending_payout.total_base_escrowed, 700);
+        assert_eq!(pending_payout.last_escrowed_at, 20);
+
+        pending_payout.quote_amount = u64::MAX;
+        assert!(pending_payout.credit(1, 0, 30).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+/// Farm rewards owed to one investor whose reward account could not receive them
+/// (missing, frozen or not a token account of the reward mint). The tokens stay
+/// in the reward treasury until the investor withdraws them with
+/// `claim_pending_reward_payout`.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingRewardPayout {
+    /// The vault this escrow belongs to
+    pub vault: Pubkey,
+
+    /// Stream recipient the rewards are owed to
+    pub investor: Pubkey,
+
+    /// Reward token mint
+    pub reward_mint: Pubkey,
+
+    /// cp-amm reward index the rewards were distributed under
+    pub reward_index: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Reward tokens owed
+    pub amount: u64,
+
+    /// Total lifetime reward tokens escrowed
+    pub total_escrowed: u64,
+
+    /// Timestamp of the last escrowed payout
+    pub last_escrowed_at: i64,
+}
+
+impl PendingRewardPayout {
+    pub const SEEDS_PREFIX: &'static [u8] = crate::constants::PENDING_REWARD_PAYOUT_SEED;
+
+    /// Record a reward payout that could not be delivered
+    pub fn credit(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        self.amount = self.amount
+            .checked_add(amount)
+            .ok_or(crate::error::HonouraryError::MathOverflow)?;
+        self.total_escrowed = self.total_escrowed.saturating_add(amount);
+        self.last_escrowed_at = current_time;
+        Ok(())
+    }
+
+    /// Empty the escrow, returning the amount owed
+    pub fn take(&mut self) -> u64 {
+        std::mem::take(&mut self.amount)
+    }
+}
@@ -1,4 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_lang::prelude::borsh;
+use crate::constants::{
+    BASIS_POINTS_DIVISOR,
+    MAX_POLICY_SIGNERS,
+    MAX_REMAINDER_RECIPIENTS,
+    MAX_SHARE_CURVE_POINTS,
+    NUM_POOL_REWARDS,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct PolicyParams {
@@ -8,13 +16,203 @@ pub struct PolicyParams {
     pub min_payout_lamports: u64,
     pub y0_total_allocation: u64, // Total tokens minted at TGE
     pub total_investors: u32, // Total number of investors for pagination validation
+    pub update_delay_seconds: i64, // Timelock between staging and applying a policy update
+    pub distribution_mode: DistributionMode,
+    pub cadence: DistributionCadence,
+    pub remainder_recipients: Vec<RemainderRecipient>, // Empty sends the whole remainder to creator_wallet
+    pub base_daily_cap_lamports: Option<u64>, // Base-token cap (BothTokens vaults)
+    pub base_min_payout_lamports: u64, // Base-token dust threshold (BothTokens vaults)
+    pub swap_base_fees: bool, // Swap claimed base fees into quote instead of distributing them
+    pub max_swap_slippage_bps: u16, // Allowed shortfall vs the pool spot price, incl. trading fee
+    pub compound_bps: u16, // Share of the creator remainder reinvested into the position
+    pub create_payout_atas: bool, // Let pending payout claims create the investor's ATA from the rent reserve
+    pub cap_overflow_mode: CapOverflowMode, // Where payouts cut by the daily caps go
+    pub guardian: Option<Pubkey>, // Key allowed to pause and unpause the vault besides the admins
+    pub cranker_reward_bps: u16, // Share of each day's quote fees paid to push-mode crankers
+    pub max_cranker_reward_per_page: u64, // Absolute cap on the cranker reward of one page
+    pub locked_amount_source: LockedAmountSource, // Kind of account the investors' locked balances are read from
+    pub weighting_mode: WeightingMode, // Pro-rata weight: locked amount at the day's start or averaged over the period
+    pub share_curve: Vec<ShareCurvePoint>, // Empty keeps investor_fee_share_bps fixed
+    pub reward_min_payout_lamports: [u64; NUM_POOL_REWARDS], // Dust threshold of each pool farm reward
 }
 
+/// A creator remainder recipient and its share of the remainder
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct RemainderRecipient {
+    pub wallet: Pubkey,
+    pub bps: u16, // Share of the remainder in basis points; all recipients sum to 10000
+}
+
+/// A point of the investor share curve: the investor fee share at `timestamp`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct ShareCurvePoint {
+    pub timestamp: i64,
+    pub bps: u16, // Investor fee share in basis points (0-10000)
+}
+
+/// How the investor share of each day's fees reaches investors
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum DistributionMode {
+    /// The crank pages through investors and transfers to their ATAs
+    Push,
+    /// The crank reserves the investor share; investors claim it from a
+    /// committed `DistributionEpoch` with a Merkle proof
+    MerkleClaim,
+}
+
+/// What happens to investor payouts cut by the daily cap
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum CapOverflowMode {
+    /// Added to the day's creator remainder
+    ToCreator,
+    /// Owed to investors: added to the next day's investor pool, on top of
+    /// the investor share of that day's fees
+    CarryToNextDay,
+    /// Burned from the vault treasury
+    Burn,
+}
+
+/// Kind of lock account registered for every investor (`InvestorEntry::stream`)
+/// and read by `snapshot_locked`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum LockedAmountSource {
+    /// Streamflow stream vesting the vault's base token
+    Streamflow,
+    /// cp-amm `Vesting` account of a locked position; locked amounts (and
+    /// `y0_total_allocation`) are then in liquidity units
+    CpAmmVesting,
+    /// fee_router `InvestorSchedule` set by the policy authority
+    Schedule,
+}
+
+/// What an investor's pro-rata weight for a day is
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum WeightingMode {
+    /// Amount locked at the day's start
+    Instantaneous,
+    /// Average amount locked over the distribution period ending at the day's
+    /// start, so the weight does not hinge on where an unlock falls
+    TimeWeighted,
+}
+
+/// How often a new distribution day may start
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum DistributionCadence {
+    /// Every `period` seconds, keeping the phase of the previous day's start:
+    /// windows begin at that start plus a multiple of `period`. A fresh vault's
+    /// phase is the unix epoch, and a period change keeps the existing phase.
+    Seconds { period: i64 },
+    /// Every `period` slots
+    Slots { period: u64 },
+    /// Every `period` seconds on boundaries aligned to `anchor_hour`:00 UTC
+    AnchoredUtc { period: i64, anchor_hour: u8 },
+}
+
+impl Default for DistributionCadence {
+    fn default() -> Self {
+        DistributionCadence::Seconds { period: crate::constants::SECONDS_PER_DAY }
+    }
+}
+
+impl DistributionCadence {
+    pub fn validate(&self) -> Result<()> {
+        let valid = match *self {
+            DistributionCadence::Seconds { period } => period > 0,
+            DistributionCadence::Slots { period } => period > 0,
+            DistributionCadence::AnchoredUtc { period, anchor_hour } => period > 0 && anchor_hour < 24,
+        };
+        require!(valid, crate::error::HonouraryError::InvalidPoolConfiguration);
+        Ok(())
+    }
+
+    /// Start of the window containing `(timestamp, slot)`, given the previous window start.
+    ///
+    /// Windows start on period boundaries rather than at the crank time, so a late
+    /// crank does not push back the rest of the schedule.
+    pub fn window_start(
+        &self,
+        last_timestamp: i64,
+        last_slot: u64,
+        timestamp: i64,
+        slot: u64
+    ) -> (i64, u64) {
+        match *self {
+            DistributionCadence::Seconds { period } => {
+                let elapsed = timestamp.saturating_sub(last_timestamp).max(0);
+                (last_timestamp + elapsed - elapsed % period, slot)
+            }
+            DistributionCadence::Slots { period } => {
+                let elapsed = slot.saturating_sub(last_slot);
+                (timestamp, last_slot + elapsed - elapsed % period)
+            }
+            DistributionCadence::AnchoredUtc { period, anchor_hour } => {
+                let anchor = anchor_hour as i64 * 3600;
+                (timestamp - (timestamp - anchor).rem_euclid(period), slot)
+            }
+        }
+    }
+
+    /// Whether `(timestamp, slot)` falls in a later window than the previous one
+    pub fn is_due(&self, last_timestamp: i64, last_slot: u64, timestamp: i64, slot: u64) -> bool {
+        let (window_ts, window_slot) = self.window_start(last_timestamp, last_slot, timestamp, slot);
+        match self {
+            DistributionCadence::Slots { .. } => window_slot > last_slot,
+            _ => window_ts > last_timestamp,
+        }
+    }
+}
+
+/// Honorary position change staged by `stage_position_operation`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum PositionOperation {
+    /// Withdraw `liquidity_delta` of principal from the honorary position into
+    /// the given quote and base token accounts
+    RemoveLiquidity { liquidity_delta: u128, quote_destination: Pubkey, base_destination: Pubkey },
+    /// Close the (emptied) honorary position and re-create it on `new_pool`
+    Migrate { new_pool: Pubkey },
+}
+
+/// Policy changes staged by `update_policy`; `None` leaves a field unchanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct PolicyUpdateParams {
+    pub investor_fee_share_bps: Option<u16>,
+    pub daily_cap_lamports: Option<Option<u64>>, // Some(None) removes the cap
+    pub min_payout_lamports: Option<u64>,
+    pub creator_wallet: Option<Pubkey>,
+    pub total_investors: Option<u32>,
+    pub distribution_mode: Option<DistributionMode>,
+    pub cadence: Option<DistributionCadence>,
+    #[max_len(MAX_REMAINDER_RECIPIENTS)]
+    pub remainder_recipients: Option<Vec<RemainderRecipient>>,
+    pub base_daily_cap_lamports: Option<Option<u64>>,
+    pub base_min_payout_lamports: Option<u64>,
+    pub swap_base_fees: Option<bool>,
+    pub max_swap_slippage_bps: Option<u16>,
+    pub compound_bps: Option<u16>,
+    pub create_payout_atas: Option<bool>,
+    pub cap_overflow_mode: Option<CapOverflowMode>,
+    pub guardian: Option<Option<Pubkey>>, // Some(None) removes the guardian
+    pub cranker_reward_bps: Option<u16>,
+    pub max_cranker_reward_per_page: Option<u64>,
+    pub locked_amount_source: Option<LockedAmountSource>,
+    pub weighting_mode: Option<WeightingMode>,
+    #[max_len(MAX_SHARE_CURVE_POINTS)]
+    pub share_curve: Option<Vec<ShareCurvePoint>>, // Some(vec![]) returns to the fixed share
+    pub reward_min_payout_lamports: Option<[u64; NUM_POOL_REWARDS]>,
+}
+
+/// Distribution policy of one vault.
+///
+/// The layout is not versioned and has no realloc or migration path: a vault set
+/// up by a build with another layout must be recreated (see the CHANGELOG).
 #[account]
 #[derive(InitSpace)]
 pub struct Policy {
     /// The vault this policy applies to
     pub vault: Pubkey,
+
+    /// Authority allowed to manage the policy and investor registry
+    pub authority: Pubkey,
     
     /// Creator wallet to receive remainder fees
     pub creator_wallet: Pubkey,
@@ -43,6 +241,97 @@ pub struct Policy {
 
     /// Policy last updated timestamp
     pub updated_at: i64,
+
+    /// Minimum delay in seconds between staging and applying a policy update
+    pub update_delay_seconds: i64,
+
+    /// Update staged by the authority, waiting for its timelock
+    pub pending_update: Option<PolicyUpdateParams>,
+
+    /// Earliest timestamp at which the pending update may be applied
+    pub pending_update_eta: i64,
+
+    /// Authority proposed by `propose_policy_authority`, pending acceptance
+    pub pending_authority: Option<Pubkey>,
+
+    /// Position operation staged by the authority, waiting for its timelock
+    pub pending_position_operation: Option<PositionOperation>,
+
+    /// Earliest timestamp at which the pending position operation may execute
+    pub pending_position_operation_eta: i64,
+
+    /// Optional M-of-N admin signer set (only the first `admin_signer_count` are used)
+    pub admin_signers: [Pubkey; MAX_POLICY_SIGNERS],
+
+    /// Number of configured admin signers
+    pub admin_signer_count: u8,
+
+    /// Signatures required from `admin_signers` for admin actions (0 = single authority)
+    pub admin_threshold: u8,
+
+    /// Push payouts or Merkle claims
+    pub distribution_mode: DistributionMode,
+
+    /// How often a new distribution day may start
+    pub cadence: DistributionCadence,
+
+    /// Creator remainder split (only the first `remainder_recipient_count` are used)
+    pub remainder_recipients: [RemainderRecipient; MAX_REMAINDER_RECIPIENTS],
+
+    /// Number of remainder recipients (0 = whole remainder to `creator_wallet`)
+    pub remainder_recipient_count: u8,
+
+    /// Optional daily base-token distribution cap (BothTokens vaults)
+    pub base_daily_cap_lamports: Option<u64>,
+
+    /// Minimum base-token payout threshold (BothTokens vaults)
+    pub base_min_payout_lamports: u64,
+
+    /// Swap claimed base fees into quote through the pool instead of distributing them
+    pub swap_base_fees: bool,
+
+    /// Maximum swap shortfall against the pool spot price, in basis points
+    pub max_swap_slippage_bps: u16,
+
+    /// Share of each day's creator remainder added back as position liquidity, in basis points
+    pub compound_bps: u16,
+
+    /// Whether `claim_pending_payout` may create a missing investor ATA, paid from the rent reserve
+    pub create_payout_atas: bool,
+
+    /// Settlement of investor payouts cut by the daily caps
+    pub cap_overflow_mode: CapOverflowMode,
+
+    /// Key allowed to pause and unpause the vault in addition to the admins
+    pub guardian: Option<Pubkey>,
+
+    /// Whether cranks and liquidity operations are halted
+    pub paused: bool,
+
+    /// Timestamp the vault was last paused
+    pub paused_at: i64,
+
+    /// Share of each day's claimed quote set aside for push-mode crankers, in basis points
+    pub cranker_reward_bps: u16,
+
+    /// Most quote a single crank page can earn
+    pub max_cranker_reward_per_page: u64,
+
+    /// Kind of account investors' locked balances are read from
+    pub locked_amount_source: LockedAmountSource,
+
+    /// How investors' pro-rata weights are derived from their locked balances
+    pub weighting_mode: WeightingMode,
+
+    /// Piecewise-linear investor fee share over time (only the first
+    /// `share_curve_point_count` are used); replaces `investor_fee_share_bps`
+    pub share_curve: [ShareCurvePoint; MAX_SHARE_CURVE_POINTS],
+
+    /// Number of share curve points (0 = fixed `investor_fee_share_bps`)
+    pub share_curve_point_count: u8,
+
+    /// Minimum reward payout threshold of each pool farm reward, by reward index
+    pub reward_min_payout_lamports: [u64; NUM_POOL_REWARDS],
 }
 
 impl Policy {
@@ -56,8 +345,249 @@ impl Policy {
         ]
     }
     
-    /// Calculate eligible investor share based on locked percentage
-    pub fn calculate_eligible_investor_share(&self, locked_total: u64) -> u16 {
+    /// Ensure an admin action is authorized.
+    ///
+    /// Without a signer set, `authority` must be the policy authority. With a signer set,
+    /// at least `admin_threshold` distinct listed signers must sign the transaction, either
+    /// as `authority` or as signer accounts in `remaining_accounts`.
+    pub fn verify_admin(&self, authority: &AccountInfo, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        if self.admin_threshold == 0 {
+            require!(authority.is_signer, crate::error::HonouraryError::UnauthorizedPolicyAuthority);
+            require_keys_eq!(
+                *authority.key,
+                self.authority,
+                crate::error::HonouraryError::UnauthorizedPolicyAuthority
+            );
+            return Ok(());
+        }
+
+        let listed = &self.admin_signers[..self.admin_signer_count as usize];
+        let mut approved = [false; MAX_POLICY_SIGNERS];
+
+        let transaction_signers = std::iter::once(authority)
+            .map(|account| (account.key, account.is_signer))
+            .chain(remaining_accounts.iter().map(|account| (account.key, account.is_signer)));
+
+        for (key, is_signer) in transaction_signers {
+            if !is_signer {
+                continue;
+            }
+            if let Some(position) = listed.iter().position(|signer| signer == key) {
+                approved[position] = true;
+            }
+        }
+
+        let approvals = approved.iter().filter(|approved| **approved).count();
+        require!(
+            approvals >= self.admin_threshold as usize,
+            crate::error::HonouraryError::InsufficientAdminSigners
+        );
+
+        Ok(())
+    }
+
+    /// Ensure a pause action is authorized: the guardian alone, or the admins as for
+    /// any other admin action.
+    pub fn verify_guardian(&self, authority: &AccountInfo, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        if authority.is_signer && self.guardian == Some(*authority.key) {
+            return Ok(());
+        }
+        self.verify_admin(authority, remaining_accounts)
+    }
+
+    /// Reject cranks and liquidity operations while the vault is paused
+    pub fn require_not_paused(&self) -> Result<()> {
+        require!(!self.paused, crate::error::HonouraryError::VaultPaused);
+        Ok(())
+    }
+
+    /// Consume the staged position operation once its timelock has elapsed;
+    /// `operation` must be exactly what was staged
+    pub fn take_position_operation(
+        &mut self,
+        operation: PositionOperation,
+        current_time: i64
+    ) -> Result<()> {
+        let staged = self.pending_position_operation.ok_or(
+            crate::error::HonouraryError::PositionOperationNotStaged
+        )?;
+        require!(staged == operation, crate::error::HonouraryError::PositionOperationMismatch);
+        require!(
+            current_time >= self.pending_position_operation_eta,
+            crate::error::HonouraryError::PolicyTimelockNotElapsed
+        );
+
+        self.pending_position_operation = None;
+        self.pending_position_operation_eta = 0;
+        Ok(())
+    }
+
+    /// Apply a staged update to the policy fields
+    pub fn apply_update(&mut self, update: &PolicyUpdateParams) {
+        if let Some(investor_fee_share_bps) = update.investor_fee_share_bps {
+            self.investor_fee_share_bps = investor_fee_share_bps;
+        }
+        if let Some(daily_cap_lamports) = update.daily_cap_lamports {
+            self.daily_cap_lamports = daily_cap_lamports;
+        }
+        if let Some(min_payout_lamports) = update.min_payout_lamports {
+            self.min_payout_lamports = min_payout_lamports;
+        }
+        if let Some(creator_wallet) = update.creator_wallet {
+            self.creator_wallet = creator_wallet;
+        }
+        if let Some(total_investors) = update.total_investors {
+            self.total_investors = total_investors;
+        }
+        if let Some(distribution_mode) = update.distribution_mode {
+            self.distribution_mode = distribution_mode;
+        }
+        if let Some(cadence) = update.cadence {
+            self.cadence = cadence;
+        }
+        if let Some(remainder_recipients) = &update.remainder_recipients {
+            self.store_remainder_recipients(remainder_recipients);
+        }
+        if let Some(base_daily_cap_lamports) = update.base_daily_cap_lamports {
+            self.base_daily_cap_lamports = base_daily_cap_lamports;
+        }
+        if let Some(base_min_payout_lamports) = update.base_min_payout_lamports {
+            self.base_min_payout_lamports = base_min_payout_lamports;
+        }
+        if let Some(swap_base_fees) = update.swap_base_fees {
+            self.swap_base_fees = swap_base_fees;
+        }
+        if let Some(max_swap_slippage_bps) = update.max_swap_slippage_bps {
+            self.max_swap_slippage_bps = max_swap_slippage_bps;
+        }
+        if let Some(compound_bps) = update.compound_bps {
+            self.compound_bps = compound_bps;
+        }
+        if let Some(create_payout_atas) = update.create_payout_atas {
+            self.create_payout_atas = create_payout_atas;
+        }
+        if let Some(cap_overflow_mode) = update.cap_overflow_mode {
+            self.cap_overflow_mode = cap_overflow_mode;
+        }
+        if let Some(guardian) = update.guardian {
+            self.guardian = guardian;
+        }
+        if let Some(cranker_reward_bps) = update.cranker_reward_bps {
+            self.cranker_reward_bps = cranker_reward_bps;
+        }
+        if let Some(max_cranker_reward_per_page) = update.max_cranker_reward_per_page {
+            self.max_cranker_reward_per_page = max_cranker_reward_per_page;
+        }
+        if let Some(locked_amount_source) = update.locked_amount_source {
+            self.locked_amount_source = locked_amount_source;
+        }
+        if let Some(weighting_mode) = update.weighting_mode {
+            self.weighting_mode = weighting_mode;
+        }
+        if let Some(share_curve) = &update.share_curve {
+            self.store_share_curve(share_curve);
+        }
+        if let Some(reward_min_payout_lamports) = update.reward_min_payout_lamports {
+            self.reward_min_payout_lamports = reward_min_payout_lamports;
+        }
+    }
+
+    /// Configured creator remainder recipients
+    pub fn remainder_recipients(&self) -> &[RemainderRecipient] {
+        &self.remainder_recipients[..self.remainder_recipient_count as usize]
+    }
+
+    /// Check a remainder split: distinct wallets with non-zero weights summing to 100%
+    pub fn validate_remainder_recipients(recipients: &[RemainderRecipient]) -> Result<()> {
+        if recipients.is_empty() {
+            return Ok(());
+        }
+        require!(
+            recipients.len() <= MAX_REMAINDER_RECIPIENTS,
+            crate::error::HonouraryError::InvalidRemainderRecipients
+        );
+
+        let mut total_bps = 0u64;
+        for (i, recipient) in recipients.iter().enumerate() {
+            require!(
+                recipient.wallet != Pubkey::default() && recipient.bps > 0,
+                crate::error::HonouraryError::InvalidRemainderRecipients
+            );
+            require!(
+                !recipients[..i].iter().any(|other| other.wallet == recipient.wallet),
+                crate::error::HonouraryError::InvalidRemainderRecipients
+            );
+            total_bps += recipient.bps as u64;
+        }
+        require!(
+            total_bps == BASIS_POINTS_DIVISOR,
+            crate::error::HonouraryError::InvalidRemainderRecipients
+        );
+
+        Ok(())
+    }
+
+    /// Store a remainder split already checked by `validate_remainder_recipients`
+    pub fn store_remainder_recipients(&mut self, recipients: &[RemainderRecipient]) {
+        self.remainder_recipients = [RemainderRecipient::default(); MAX_REMAINDER_RECIPIENTS];
+        self.remainder_recipients[..recipients.len()].copy_from_slice(recipients);
+        self.remainder_recipient_count = recipients.len() as u8;
+    }
+
+    /// Configured share curve points
+    pub fn share_curve(&self) -> &[ShareCurvePoint] {
+        &self.share_curve[..self.share_curve_point_count as usize]
+    }
+
+    /// Check a share curve: at most `MAX_SHARE_CURVE_POINTS` points with
+    /// non-decreasing timestamps (two points at one timestamp make a step) and
+    /// shares of at most 100%
+    pub fn validate_share_curve(points: &[ShareCurvePoint]) -> Result<()> {
+        require!(points.len() <= MAX_SHARE_CURVE_POINTS, crate::error::HonouraryError::InvalidShareCurve);
+        require!(
+            points.iter().all(|point| point.bps <= BASIS_POINTS_DIVISOR as u16) &&
+                points.windows(2).all(|pair| pair[0].timestamp <= pair[1].timestamp),
+            crate::error::HonouraryError::InvalidShareCurve
+        );
+        Ok(())
+    }
+
+    /// Store a share curve already checked by `validate_share_curve`
+    pub fn store_share_curve(&mut self, points: &[ShareCurvePoint]) {
+        self.share_curve = [ShareCurvePoint::default(); MAX_SHARE_CURVE_POINTS];
+        self.share_curve[..points.len()].copy_from_slice(points);
+        self.share_curve_point_count = points.len() as u8;
+    }
+
+    /// Maximum investor fee share at `timestamp`: `investor_fee_share_bps`, or the
+    /// share curve interpolated linearly between its points and held flat
+    /// before the first and after the last
+    pub fn investor_fee_share_bps_at(&self, timestamp: i64) -> u16 {
+        let points = self.share_curve();
+        let Some(first) = points.first() else {
+            return self.investor_fee_share_bps;
+        };
+
+        // First point strictly after `timestamp`; at a step the later point applies
+        let next = points.partition_point(|point| point.timestamp <= timestamp);
+        if next == 0 {
+            return first.bps;
+        }
+        if next == points.len() {
+            return points[next - 1].bps;
+        }
+
+        let (from, to) = (points[next - 1], points[next]);
+        // Widened before subtracting: points may sit anywhere in the i64 range
+        let elapsed = timestamp as i128 - from.timestamp as i128;
+        let span = to.timestamp as i128 - from.timestamp as i128;
+        let bps = from.bps as i128 + ((to.bps as i128 - from.bps as i128) * elapsed) / span;
+        bps as u16
+    }
+
+    /// Calculate eligible investor share based on locked percentage, capped by
+    /// the investor fee share in force at the day's start
+    pub fn calculate_eligible_investor_share(&self, locked_total: u64, day_start_ts: i64) -> u16 {
         if self.y0_total_allocation == 0 {
             return 0;
         }
@@ -66,6 +596,238 @@ impl Policy {
             / self.y0_total_allocation as u128;
         let locked_fraction = std::cmp::min(locked_fraction, crate::constants::BASIS_POINTS_DIVISOR as u128) as u16;
         
-        std::cmp::min(self.investor_fee_share_bps, locked_fraction)
+        std::cmp::min(self.investor_fee_share_bps_at(day_start_ts), locked_fraction)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy_with_signers(signers: &[Pubkey], threshold: u8) -> Policy {
+        let mut admin_signers = [Pubkey::default(); MAX_POLICY_SIGNERS];
+        admin_signers[..signers.len()].copy_from_slice(signers);
+
+        Policy {
+            vault: Pubkey::default(),
+            authority: Pubkey::new_unique(),
+            creator_wallet: Pubkey::default(),
+            investor_fee_share_bps: 0,
+            daily_cap_lamports: None,
+            min_payout_lamports: 0,
+            y0_total_allocation: 0,
+            total_investors: 0,
+            bump: 0,
+            created_at: 0,
+            updated_at: 0,
+            update_delay_seconds: 0,
+            pending_update: None,
+            pending_update_eta: 0,
+            pending_authority: None,
+            pending_position_operation: None,
+            pending_position_operation_eta: 0,
+            admin_signers,
+            admin_signer_count: signers.len() as u8,
+            admin_threshold: threshold,
+            distribution_mode: DistributionMode::Push,
+            cadence: DistributionCadence::default(),
+            remainder_recipients: [RemainderRecipient::default(); MAX_REMAINDER_RECIPIENTS],
+            remainder_recipient_count: 0,
+            base_daily_cap_lamports: None,
+            base_min_payout_lamports: 0,
+            swap_base_fees: false,
+            max_swap_slippage_bps: 0,
+            compound_bps: 0,
+            create_payout_atas: false,
+            cap_overflow_mode: CapOverflowMode::ToCreator,
+            guardian: None,
+            paused: false,
+            paused_at: 0,
+            cranker_reward_bps: 0,
+            max_cranker_reward_per_page: 0,
+            locked_amount_source: LockedAmountSource::Streamflow,
+            weighting_mode: WeightingMode::Instantaneous,
+            share_curve: [ShareCurvePoint::default(); MAX_SHARE_CURVE_POINTS],
+            share_curve_point_count: 0,
+            reward_min_payout_lamports: [0; NUM_POOL_REWARDS],
+        }
+    }
+
+    #[test]
+    fn test_verify_admin_requires_threshold_of_distinct_signers() {
+        let owner = Pubkey::default();
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let policy = policy_with_signers(&keys, 2);
+
+        let mut lamports = [0u64; 4];
+        let mut data: [[u8; 0]; 4] = [[]; 4];
+        let [l0, l1, l2, l3] = &mut lamports;
+        let [d0, d1, d2, d3] = &mut data;
+        let first = AccountInfo::new(&keys[0], true, false, l0, d0, &owner, false, 0);
+        let first_again = AccountInfo::new(&keys[0], true, false, l1, d1, &owner, false, 0);
+        let second_unsigned = AccountInfo::new(&keys[1], false, false, l2, d2, &owner, false, 0);
+        let third = AccountInfo::new(&keys[2], true, false, l3, d3, &owner, false, 0);
+
+        // Duplicates and non-signing listed keys do not count towards the threshold
+        assert!(policy.verify_admin(&first, &[first_again.clone(), second_unsigned]).is_err());
+        assert!(policy.verify_admin(&first, &[first_again, third]).is_ok());
+
+        // The policy authority alone is not enough once a signer set is configured
+        let authority = policy.authority;
+        let mut lamports = 0u64;
+        let mut data: [u8; 0] = [];
+        let authority_info = AccountInfo::new(&authority, true, false, &mut lamports, &mut data, &owner, false, 0);
+        assert!(policy.verify_admin(&authority_info, &[]).is_err());
+    }
+
+    #[test]
+    fn test_verify_guardian_accepts_guardian_or_admin() {
+        let owner = Pubkey::default();
+        let guardian = Pubkey::new_unique();
+        let mut policy = policy_with_signers(&[], 0);
+        let authority = policy.authority;
+
+        let mut lamports = [0u64; 3];
+        let mut data: [[u8; 0]; 3] = [[]; 3];
+        let [l0, l1, l2] = &mut lamports;
+        let [d0, d1, d2] = &mut data;
+        let guardian_info = AccountInfo::new(&guardian, true, false, l0, d0, &owner, false, 0);
+        let guardian_unsigned = AccountInfo::new(&guardian, false, false, l1, d1, &owner, false, 0);
+        let authority_info = AccountInfo::new(&authority, true, false, l2, d2, &owner, false, 0);
+
+        assert!(policy.verify_guardian(&guardian_info, &[]).is_err());
+        assert!(policy.verify_guardian(&authority_info, &[]).is_ok());
+
+        policy.guardian = Some(guardian);
+        assert!(policy.verify_guardian(&guardian_info, &[]).is_ok());
+        assert!(policy.verify_guardian(&guardian_unsigned, &[]).is_err());
+
+        // The guardian cannot stand in for the admins elsewhere
+        assert!(policy.verify_admin(&guardian_info, &[]).is_err());
+    }
+
+    #[test]
+    fn test_share_curve_interpolates_between_points() {
+        const MONTH: i64 = 30 * 86_400;
+        let mut policy = policy_with_signers(&[], 0);
+        policy.investor_fee_share_bps = 7_000;
+        policy.y0_total_allocation = 1_000;
+        assert_eq!(policy.investor_fee_share_bps_at(0), 7_000);
+
+        // 50% for months 0-6, decaying to 10% by month 24, then a step down to 5%
+        let curve = [
+            ShareCurvePoint { timestamp: 0, bps: 5_000 },
+            ShareCurvePoint { timestamp: 6 * MONTH, bps: 5_000 },
+            ShareCurvePoint { timestamp: 24 * MONTH, bps: 1_000 },
+            ShareCurvePoint { timestamp: 30 * MONTH, bps: 1_000 },
+            ShareCurvePoint { timestamp: 30 * MONTH, bps: 500 },
+        ];
+        Policy::validate_share_curve(&curve).unwrap();
+        policy.store_share_curve(&curve);
+
+        assert_eq!(policy.investor_fee_share_bps_at(-1), 5_000);
+        assert_eq!(policy.investor_fee_share_bps_at(3 * MONTH), 5_000);
+        assert_eq!(policy.investor_fee_share_bps_at(15 * MONTH), 3_000);
+        assert_eq!(policy.investor_fee_share_bps_at(24 * MONTH), 1_000);
+        assert_eq!(policy.investor_fee_share_bps_at(30 * MONTH - 1), 1_000);
+        assert_eq!(policy.investor_fee_share_bps_at(30 * MONTH), 500);
+        assert_eq!(policy.investor_fee_share_bps_at(i64::MAX), 500);
+
+        // Still capped by the locked fraction (20% here)
+        assert_eq!(policy.calculate_eligible_investor_share(200, 15 * MONTH), 2_000);
+        assert_eq!(policy.calculate_eligible_investor_share(1_000, 15 * MONTH), 3_000);
+
+        let unordered = [curve[2], curve[1]];
+        assert!(Policy::validate_share_curve(&unordered).is_err());
+        assert!(Policy::validate_share_curve(&[ShareCurvePoint { timestamp: 0, bps: 10_001 }]).is_err());
+    }
+
+    #[test]
+    fn test_share_curve_spanning_the_i64_range() {
+        let mut policy = policy_with_signers(&[], 0);
+        let curve = [
+            ShareCurvePoint { timestamp: i64::MIN, bps: 10_000 },
+            ShareCurvePoint { timestamp: i64::MAX, bps: 0 },
+        ];
+        Policy::validate_share_curve(&curve).unwrap();
+        policy.store_share_curve(&curve);
+
+        assert_eq!(policy.investor_fee_share_bps_at(i64::MIN), 10_000);
+        assert_eq!(policy.investor_fee_share_bps_at(0), 5_000);
+        assert_eq!(policy.investor_fee_share_bps_at(i64::MAX), 0);
+    }
+
+    #[test]
+    fn test_position_operation_requires_match_and_elapsed_timelock() {
+        let mut policy = policy_with_signers(&[], 0);
+        let (quote_destination, base_destination) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let remove = PositionOperation::RemoveLiquidity { liquidity_delta: 1_000, quote_destination, base_destination };
+        assert!(policy.take_position_operation(remove, 0).is_err());
+
+        policy.pending_position_operation = Some(remove);
+        policy.pending_position_operation_eta = 100;
+
+        // Different parameters or an unexpired timelock leave it staged
+        let other = PositionOperation::RemoveLiquidity { liquidity_delta: 2_000, quote_destination, base_destination };
+        assert!(policy.take_position_operation(other, 200).is_err());
+        let redirected = PositionOperation::RemoveLiquidity {
+            liquidity_delta: 1_000,
+            quote_destination: Pubkey::new_unique(),
+            base_destination,
+        };
+        assert!(policy.take_position_operation(redirected, 200).is_err());
+        assert!(policy.take_position_operation(remove, 99).is_err());
+        assert!(policy.pending_position_operation.is_some());
+
+        policy.take_position_operation(remove, 100).unwrap();
+        assert!(policy.pending_position_operation.is_none());
+    }
+
+    #[test]
+    fn test_seconds_cadence_keeps_phase_when_crank_is_late() {
+        let weekly = DistributionCadence::Seconds { period: 7 * 86_400 };
+        let first = 1_700_000_000;
+
+        // Cranked 3 hours late: the window still starts on the weekly boundary
+        let (window, _) = weekly.window_start(first, 0, first + 7 * 86_400 + 10_800, 0);
+        assert_eq!(window, first + 7 * 86_400);
+        assert!(!weekly.is_due(first, 0, first + 7 * 86_400 - 1, 0));
+        assert!(weekly.is_due(first, 0, first + 7 * 86_400, 0));
+    }
+
+    #[test]
+    fn test_seconds_cadence_period_change_keeps_phase() {
+        let weekly_start = 1_700_000_000;
+        assert_ne!(weekly_start % 86_400, 0);
+
+        // Switching from weekly to daily: daily windows follow the last weekly start,
+        // not midnight UTC
+        let daily = DistributionCadence::Seconds { period: 86_400 };
+        let (window, _) = daily.window_start(weekly_start, 0, weekly_start + 2 * 86_400 + 3_600, 0);
+        assert_eq!(window, weekly_start + 2 * 86_400);
+        assert!(!daily.is_due(weekly_start, 0, weekly_start + 86_399, 0));
+        assert!(daily.is_due(weekly_start, 0, weekly_start + 86_400, 0));
+
+        // A fresh vault starts from the epoch phase
+        let (window, _) = daily.window_start(0, 0, 19_700 * 86_400 + 5_000, 0);
+        assert_eq!(window, 19_700 * 86_400);
+    }
+
+    #[test]
+    fn test_anchored_and_slot_cadences() {
+        let daily_midnight = DistributionCadence::AnchoredUtc { period: 86_400, anchor_hour: 0 };
+        let midnight = 19_700 * 86_400;
+
+        let (window, _) = daily_midnight.window_start(0, 0, midnight + 5_000, 0);
+        assert_eq!(window, midnight);
+        assert!(!daily_midnight.is_due(midnight, 0, midnight + 86_399, 0));
+        assert!(daily_midnight.is_due(midnight, 0, midnight + 86_400, 0));
+
+        let slots = DistributionCadence::Slots { period: 1_000 };
+        assert_eq!(slots.window_start(0, 5_000, 42, 7_250), (42, 7_000));
+        assert!(!slots.is_due(0, 5_000, 42, 5_999));
+        assert!(slots.is_due(0, 5_000, 42, 6_000));
+
+        assert!(DistributionCadence::AnchoredUtc { period: 86_400, anchor_hour: 24 }.validate().is_err());
+        assert!(DistributionCadence::Seconds { period: 0 }.validate().is_err());
+    }
+}
@@ -1,4 +1,14 @@
 use anchor_lang::prelude::*;
+use anchor_lang::prelude::borsh;
+
+/// Which pool fee tokens the honorary position collects and distributes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum FeeCollection {
+    /// Quote-only pools; any base fee aborts the claim
+    QuoteOnly,
+    /// Opt-in for BothToken pools: base fees are distributed alongside quote fees
+    BothTokens,
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -12,7 +22,7 @@ pub struct InvestorFeePositionOwner {
     /// The NFT mint for this position
     pub position_mint: Pubkey,
     
-    /// The quote token mint (the only token we collect fees in)
+    /// The quote token mint
     pub quote_mint: Pubkey,
     
     /// The actual position account created in cp-amm
@@ -26,6 +36,15 @@ pub struct InvestorFeePositionOwner {
     
     /// Total fees claimed to date
     pub total_fees_claimed: u64,
+
+    /// Quote-only or both-token fee collection
+    pub fee_collection: FeeCollection,
+
+    /// Total base-token fees claimed to date (BothTokens only)
+    pub total_base_fees_claimed: u64,
+
+    /// Number of additional honorary positions (HonoraryPosition accounts 1..=count)
+    pub additional_position_count: u8,
 }
 
 impl InvestorFeePositionOwner {
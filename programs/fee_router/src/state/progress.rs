@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::state::{ CapOverflowMode, DistributionCadence };
+use crate::utils::math::{ calculate_creator_remainder, calculate_investor_fee_amount };
 
 #[account]
 #[derive(InitSpace)]
@@ -36,16 +38,107 @@ pub struct DistributionProgress {
     /// Total lifetime distributed to creator
     pub total_creator_distributed: u64,
 
-    /// Total locked amount across ALL investors for current day (calculated once on first page)
+    /// Total locked amount across ALL investors for current day (accumulated by snapshot pages)
     pub current_day_total_locked_all: u64,
 
     /// Persistent dust carried from previous day (added to next day's claimable pool)
     pub persistent_carry_over: u64,
 
-    /// Bitmap tracking which investors have been paid today
-    /// Each bit represents one investor (bit 0 = investor 0, bit 1 = investor 1, etc.)
-    /// Supports up to 2048 investors (256 bytes * 8 bits)
-    pub paid_investor_bitmap: [u8; 256],
+    /// Distribution day counter, incremented when a new day starts
+    /// Paid/snapshot bitmap shards tagged with an older day are treated as empty
+    pub current_day: u64,
+
+    /// Next investor index expected by the locked-amount snapshot
+    pub snapshot_cursor: u32,
+
+    /// Whether the locked-amount snapshot covers every investor for the current day
+    pub snapshot_sealed: bool,
+
+    /// Whether the current day's fees have been claimed (first payout page processed)
+    pub day_fees_claimed: bool,
+
+    /// Investor share reserved by Merkle-mode days that no epoch has committed yet
+    pub merkle_unallocated: u64,
+
+    /// Index of the next DistributionEpoch to be committed
+    pub next_epoch_index: u64,
+
+    /// Slot of last distribution start (used by slot-based cadences)
+    pub last_distribution_slot: u64,
+
+    // Base-token ledger, mirroring the quote fields above (BothTokens vaults only)
+
+    /// Base fees claimable in current day (claimed + carried from previous day)
+    pub base_current_day_total_claimed: u64,
+
+    /// Base tokens distributed in current day
+    pub base_current_day_distributed: u64,
+
+    /// Base dust carried between pages of the current day
+    pub base_current_day_carry_over: u64,
+
+    /// Base dust carried from the previous day
+    pub base_persistent_carry_over: u64,
+
+    /// Total lifetime base tokens distributed to investors
+    pub total_base_investor_distributed: u64,
+
+    /// Total lifetime base tokens distributed to creator
+    pub total_base_creator_distributed: u64,
+
+    /// Quote set aside for compounding that the last liquidity add did not use
+    pub compound_quote_reserve: u64,
+
+    /// Base set aside for compounding that the last liquidity add did not use
+    pub compound_base_reserve: u64,
+
+    /// Total lifetime liquidity added to the position by compounding
+    pub total_compounded_liquidity: u128,
+
+    /// Quote owed to investors in PendingPayout escrows (held in the treasury)
+    pub pending_payout_quote: u64,
+
+    /// Base owed to investors in PendingPayout escrows (held in the base treasury)
+    pub pending_payout_base: u64,
+
+    /// Quote payouts cut by the daily cap so far in the current day
+    pub current_day_cap_overflow: u64,
+
+    /// Base payouts cut by the base daily cap so far in the current day
+    pub base_current_day_cap_overflow: u64,
+
+    /// Capped quote from earlier days added to the current day's investor pool
+    pub current_day_overflow_carry_in: u64,
+
+    /// Capped base from earlier days added to the current day's investor pool
+    pub base_current_day_overflow_carry_in: u64,
+
+    /// Capped quote owed to investors on the next day (CarryToNextDay)
+    pub investor_overflow_carry: u64,
+
+    /// Capped base owed to investors on the next day (CarryToNextDay)
+    pub base_investor_overflow_carry: u64,
+
+    /// Total lifetime quote cap overflow burned (Burn)
+    pub total_cap_overflow_burned: u64,
+
+    /// Total lifetime base cap overflow burned (Burn)
+    pub total_base_cap_overflow_burned: u64,
+
+    /// Quote set aside from the current day's fees for cranker rewards
+    pub current_day_cranker_pool: u64,
+
+    /// Cranker rewards paid so far in the current day
+    pub current_day_cranker_paid: u64,
+
+    /// Total lifetime cranker rewards
+    pub total_cranker_rewards: u64,
+
+    /// Start timestamp of the previous day (start of the period time-weighted snapshots average over)
+    pub previous_distribution_ts: i64,
+
+    /// Start slot of the previous day
+    pub previous_distribution_slot: u64,
 }
 
 impl DistributionProgress {
@@ -59,78 +152,255 @@ impl DistributionProgress {
         ]
     }
     
-    /// Check if enough time has passed for next distribution
-    pub fn can_distribute(&self, current_timestamp: i64) -> bool {
+    /// Check if the policy cadence allows the next distribution
+    pub fn can_distribute(
+        &self,
+        cadence: &DistributionCadence,
+        current_timestamp: i64,
+        current_slot: u64
+    ) -> bool {
         if self.day_completed {
-            current_timestamp >= self.last_distribution_ts + crate::constants::SECONDS_PER_DAY
+            cadence.is_due(
+                self.last_distribution_ts,
+                self.last_distribution_slot,
+                current_timestamp,
+                current_slot
+            )
         } else {
             // Can continue same day distribution
             true
         }
     }
     
-    /// Reset for new day
-    pub fn start_new_day(&mut self, current_timestamp: i64, total_claimed: u64, total_locked_all: u64) {
-        self.last_distribution_ts = current_timestamp;
+    /// Whether the current day's locked-amount weights can be paid against: from the
+    /// snapshot's seal until the next day's snapshot opens, fee day completed or not
+    pub fn snapshot_weights_available(&self) -> bool {
+        self.snapshot_sealed || (self.day_completed && self.current_day > 0)
+    }
+
+    /// Reset for new day and open the locked-amount snapshot phase
+    /// The day starts at the cadence window boundary, not at the crank time
+    pub fn begin_snapshot(
+        &mut self,
+        cadence: &DistributionCadence,
+        current_timestamp: i64,
+        current_slot: u64
+    ) {
+        let (window_ts, window_slot) = cadence.window_start(
+            self.last_distribution_ts,
+            self.last_distribution_slot,
+            current_timestamp,
+            current_slot
+        );
+        self.previous_distribution_ts = self.last_distribution_ts;
+        self.previous_distribution_slot = self.last_distribution_slot;
+        self.last_distribution_ts = window_ts;
+        self.last_distribution_slot = window_slot;
         self.current_day_distributed = 0;
         self.current_day_carry_over = 0;
         self.pagination_cursor = 0;
         self.day_completed = false;
+        self.current_day_total_claimed = 0;
+        self.current_day_total_locked_all = 0;
+
+        self.base_current_day_total_claimed = 0;
+        self.base_current_day_distributed = 0;
+        self.base_current_day_carry_over = 0;
+
+        self.current_day_cap_overflow = 0;
+        self.base_current_day_cap_overflow = 0;
+        self.current_day_overflow_carry_in = 0;
+        self.base_current_day_overflow_carry_in = 0;
+
+        self.current_day_cranker_pool = 0;
+        self.current_day_cranker_paid = 0;
+
+        self.snapshot_cursor = 0;
+        self.snapshot_sealed = false;
+        self.day_fees_claimed = false;
+
+        // Bitmap shards reset lazily when first touched on the new day
+        self.current_day += 1;
+    }
 
+    /// Record the day's claimed fees once the snapshot is sealed and payouts begin
+    pub fn start_payouts(&mut self, total_claimed: u64, base_claimed: u64) {
         // Add persistent carry-over (dust from previous day) to today's claimable pool
         // This ensures dust gets redistributed instead of being lost
         self.current_day_total_claimed = total_claimed.saturating_add(self.persistent_carry_over);
-        self.current_day_total_locked_all = total_locked_all;
+        self.base_current_day_total_claimed = base_claimed.saturating_add(self.base_persistent_carry_over);
 
         // Reset persistent carry-over now that it's been added to the pool
         self.persistent_carry_over = 0;
+        self.base_persistent_carry_over = 0;
+
+        // Capped payouts owed from earlier days go to investors only
+        self.current_day_overflow_carry_in = self.investor_overflow_carry;
+        self.base_current_day_overflow_carry_in = self.base_investor_overflow_carry;
+        self.investor_overflow_carry = 0;
+        self.base_investor_overflow_carry = 0;
+
+        self.day_fees_claimed = true;
+    }
+
+    /// Investor pools for the day (quote, base): the eligible share of the
+    /// claimed fees net of the cranker pool, plus capped payouts carried in
+    /// from earlier days
+    pub fn investor_pools(&self, eligible_share_bps: u16) -> Result<(u64, u64)> {
+        let quote = calculate_investor_fee_amount(
+            self.current_day_total_claimed.saturating_sub(self.current_day_cranker_pool),
+            eligible_share_bps
+        )?
+            .checked_add(self.current_day_overflow_carry_in)
+            .ok_or(crate::error::HonouraryError::MathOverflow)?;
+        let base = calculate_investor_fee_amount(self.base_current_day_total_claimed, eligible_share_bps)?
+            .checked_add(self.base_current_day_overflow_carry_in)
+            .ok_or(crate::error::HonouraryError::MathOverflow)?;
+        Ok((quote, base))
+    }
+
+    /// Creator remainders for the day (quote, base). Dust always carries; cap
+    /// overflow only reaches the creator under `CapOverflowMode::ToCreator`.
+    /// Cranker pool left unpaid by the per-page cap goes to the creator.
+    pub fn creator_remainders(&self, mode: CapOverflowMode) -> Result<(u64, u64)> {
+        let withheld = |dust: u64, overflow: u64| match mode {
+            CapOverflowMode::ToCreator => dust,
+            CapOverflowMode::CarryToNextDay | CapOverflowMode::Burn => dust.saturating_add(overflow),
+        };
+
+        let quote = calculate_creator_remainder(
+            self.current_day_total_claimed
+                .saturating_add(self.current_day_overflow_carry_in)
+                .saturating_sub(self.current_day_cranker_paid),
+            self.current_day_distributed,
+            withheld(self.current_day_carry_over, self.current_day_cap_overflow)
+        )?;
+        let base = calculate_creator_remainder(
+            self.base_current_day_total_claimed.saturating_add(self.base_current_day_overflow_carry_in),
+            self.base_current_day_distributed,
+            withheld(self.base_current_day_carry_over, self.base_current_day_cap_overflow)
+        )?;
+        Ok((quote, base))
+    }
 
-        // Reset bitmap for new day
-        self.paid_investor_bitmap = [0u8; 256];
+    /// Cranker reward for a page covering `investors_in_page` of `total_investors`:
+    /// the page's pro-rata part of the day's cranker pool, at most `max_per_page`
+    pub fn page_cranker_reward(&self, investors_in_page: u32, total_investors: u32, max_per_page: u64) -> u64 {
+        if total_investors == 0 {
+            return 0;
+        }
+
+        let page_share = (self.current_day_cranker_pool as u128)
+            .saturating_mul(investors_in_page as u128)
+            .saturating_div(total_investors as u128) as u64;
+        let unpaid = self.current_day_cranker_pool.saturating_sub(self.current_day_cranker_paid);
+        page_share.min(max_per_page).min(unpaid)
+    }
+
+    /// Book the day's cap overflow under the policy mode (burns happen in the crank)
+    pub fn settle_cap_overflow(&mut self, mode: CapOverflowMode) {
+        match mode {
+            CapOverflowMode::ToCreator => {}
+            CapOverflowMode::CarryToNextDay => {
+                self.investor_overflow_carry =
+                    self.investor_overflow_carry.saturating_add(self.current_day_cap_overflow);
+                self.base_investor_overflow_carry =
+                    self.base_investor_overflow_carry.saturating_add(self.base_current_day_cap_overflow);
+            }
+            CapOverflowMode::Burn => {
+                self.total_cap_overflow_burned =
+                    self.total_cap_overflow_burned.saturating_add(self.current_day_cap_overflow);
+                self.total_base_cap_overflow_burned =
+                    self.total_base_cap_overflow_burned.saturating_add(self.base_current_day_cap_overflow);
+            }
+        }
     }
     
     /// Complete current day
-    pub fn complete_day(&mut self, creator_amount: u64) {
+    pub fn complete_day(&mut self, creator_amount: u64, base_creator_amount: u64) {
         self.day_completed = true;
         self.total_distributions += 1;
         self.total_creator_distributed += creator_amount;
+        self.total_base_creator_distributed += base_creator_amount;
         self.pagination_cursor = 0;
 
         // Persist current day's dust to carry forward to next day
         self.persistent_carry_over = self.current_day_carry_over;
+        self.base_persistent_carry_over = self.base_current_day_carry_over;
 
-        // Reset bitmap for next day
-        self.paid_investor_bitmap = [0u8; 256];
+        // Close out the snapshot so the next day starts with a fresh one
+        self.snapshot_sealed = false;
+        self.day_fees_claimed = false;
     }
+}
 
-    /// Check if an investor has already been paid today
-    pub fn is_investor_paid(&self, investor_index: u32) -> bool {
-        let byte_idx = (investor_index / 8) as usize;
-        let bit_idx = (investor_index % 8) as u8;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        if byte_idx >= self.paid_investor_bitmap.len() {
-            return false; // Out of bounds, treat as not paid
-        }
+    fn empty_progress() -> DistributionProgress {
+        DistributionProgress::deserialize(&mut &vec![0u8; DistributionProgress::INIT_SPACE][..]).unwrap()
+    }
 
-        (self.paid_investor_bitmap[byte_idx] & (1 << bit_idx)) != 0
+    #[test]
+    fn test_cap_overflow_modes_account_exactly() {
+        // 10_000 claimed, half to investors, 5_000 capped at 3_000
+        let mut progress = empty_progress();
+        progress.start_payouts(10_000, 0);
+        progress.current_day_distributed = 3_000;
+        progress.current_day_cap_overflow = 2_000;
+        progress.current_day_carry_over = 10;
+
+        assert_eq!(progress.creator_remainders(CapOverflowMode::ToCreator).unwrap().0, 6_990);
+        assert_eq!(progress.creator_remainders(CapOverflowMode::Burn).unwrap().0, 4_990);
+        assert_eq!(progress.creator_remainders(CapOverflowMode::CarryToNextDay).unwrap().0, 4_990);
+
+        // Carried overflow joins the next day's investor pool, never the creator's
+        progress.settle_cap_overflow(CapOverflowMode::CarryToNextDay);
+        progress.complete_day(4_990, 0);
+        progress.current_day_cap_overflow = 0;
+        progress.current_day_distributed = 0;
+        progress.current_day_carry_over = 0;
+        progress.start_payouts(1_000, 0);
+        assert_eq!(progress.investor_pools(5_000).unwrap(), (505 + 2_000, 0));
+
+        // Everything the investors do not receive still reaches the creator side once
+        progress.current_day_distributed = 2_505;
+        assert_eq!(progress.creator_remainders(CapOverflowMode::CarryToNextDay).unwrap().0, 505);
     }
 
-    /// Mark an investor as paid
-    pub fn mark_investor_paid(&mut self, investor_index: u32) -> Result<()> {
-        let byte_idx = (investor_index / 8) as usize;
-        let bit_idx = (investor_index % 8) as u8;
+    #[test]
+    fn test_cranker_pool_comes_out_before_investors() {
+        let mut progress = empty_progress();
+        progress.start_payouts(10_000, 0);
+        progress.current_day_cranker_pool = 100;
 
-        require!(
-            byte_idx < self.paid_investor_bitmap.len(),
-            crate::error::HonouraryError::InvalidPagination
-        );
+        // 3 pages over 10 investors: 30, 30, then 40 capped at 35
+        assert_eq!(progress.page_cranker_reward(3, 10, 35), 30);
+        progress.current_day_cranker_paid = 60;
+        assert_eq!(progress.page_cranker_reward(4, 10, 35), 35);
+        progress.current_day_cranker_paid = 95;
 
-        self.paid_investor_bitmap[byte_idx] |= 1 << bit_idx;
-        Ok(())
+        // Investors share the fees net of the pool; the creator gets the unpaid 5
+        assert_eq!(progress.investor_pools(5_000).unwrap().0, 4_950);
+        progress.current_day_distributed = 4_950;
+        assert_eq!(progress.creator_remainders(CapOverflowMode::ToCreator).unwrap().0, 4_955);
     }
 
-    /// Reset bitmap (called when starting new day)
-    pub fn reset_bitmap(&mut self) {
-        self.paid_investor_bitmap = [0u8; 256];
+    #[test]
+    fn test_snapshot_weights_outlive_the_fee_day() {
+        let mut progress = empty_progress();
+        progress.day_completed = true;
+        assert!(!progress.snapshot_weights_available());
+
+        progress.begin_snapshot(&DistributionCadence::Seconds { period: 86_400 }, 86_400, 0);
+        assert!(!progress.snapshot_weights_available());
+
+        progress.snapshot_sealed = true;
+        assert!(progress.snapshot_weights_available());
+
+        // Completing the fee day leaves the weights in place for reward payouts
+        progress.complete_day(0, 0);
+        assert!(progress.snapshot_weights_available());
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Marks a lock account as registered in one of the vault's investor slots, so the
+/// same stream, vesting or schedule cannot back two investor indices and be
+/// counted and paid twice. Created by `register_investor`, closed by
+/// `remove_investor`.
+#[account]
+#[derive(InitSpace)]
+pub struct RegisteredLock {
+    /// The vault this marker belongs to
+    pub vault: Pubkey,
+
+    /// The registered lock account
+    pub stream: Pubkey,
+
+    /// Global investor index the lock account is registered at
+    pub investor_index: u32,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RegisteredLock {
+    pub const SEEDS_PREFIX: &'static [u8] = crate::constants::REGISTERED_LOCK_SEED;
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use crate::constants::INVESTORS_PER_REGISTRY_PAGE;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct InvestorEntry {
+    /// Lock account backing this investor's locked balance: a Streamflow stream,
+    /// cp-amm vesting or investor schedule, per the policy's `locked_amount_source`
+    pub stream: Pubkey,
+
+    /// Recipient wallet of the lock (the investor)
+    pub recipient: Pubkey,
+
+    /// Quote token account that receives this investor's payouts
+    pub payout_ata: Pubkey,
+
+    /// Whether this slot currently holds a registered investor
+    pub active: bool,
+
+    /// Locked amount captured for this investor by the current day's snapshot
+    pub locked_snapshot: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct InvestorRegistry {
+    /// The vault this registry page belongs to
+    pub vault: Pubkey,
+
+    /// Page index (covers global investor indices
+    /// [page_index * INVESTORS_PER_REGISTRY_PAGE, (page_index + 1) * INVESTORS_PER_REGISTRY_PAGE))
+    pub page_index: u32,
+
+    /// Number of active entries in this page
+    pub active_count: u16,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Investor slots, indexed by global investor index modulo page capacity
+    pub entries: [InvestorEntry; INVESTORS_PER_REGISTRY_PAGE as usize],
+}
+
+impl InvestorRegistry {
+    pub const SEEDS_PREFIX: &'static [u8] = crate::constants::INVESTOR_REGISTRY_SEED;
+
+    /// Registry page that holds the given global investor index
+    pub fn page_for(investor_index: u32) -> u32 {
+        investor_index / INVESTORS_PER_REGISTRY_PAGE
+    }
+
+    /// Slot within its registry page for the given global investor index
+    pub fn slot_for(investor_index: u32) -> usize {
+        (investor_index % INVESTORS_PER_REGISTRY_PAGE) as usize
+    }
+
+    /// Look up the active entry for a global investor index held by this page
+    pub fn entry(&self, investor_index: u32) -> Result<&InvestorEntry> {
+        require!(
+            Self::page_for(investor_index) == self.page_index,
+            crate::error::HonouraryError::InvestorNotRegistered
+        );
+
+        let entry = &self.entries[Self::slot_for(investor_index)];
+        require!(entry.active, crate::error::HonouraryError::InvestorNotRegistered);
+
+        Ok(entry)
+    }
+
+    /// Look up the entry for a global investor index held by this page, or `None`
+    /// for a slot left empty by `remove_investor` (paged over with zero weight)
+    pub fn active_entry(&self, investor_index: u32) -> Result<Option<&InvestorEntry>> {
+        require!(
+            Self::page_for(investor_index) == self.page_index,
+            crate::error::HonouraryError::InvestorNotRegistered
+        );
+
+        let entry = &self.entries[Self::slot_for(investor_index)];
+        Ok(entry.active.then_some(entry))
+    }
+
+    /// Ensure the accounts supplied for an investor match its registry entry
+    pub fn verify_investor_accounts(
+        &self,
+        investor_index: u32,
+        stream: &Pubkey,
+        payout_ata: &Pubkey
+    ) -> Result<()> {
+        let entry = self.entry(investor_index)?;
+
+        require_keys_eq!(*stream, entry.stream, crate::error::HonouraryError::InvestorAccountMismatch);
+        require_keys_eq!(
+            *payout_ata,
+            entry.payout_ata,
+            crate::error::HonouraryError::InvestorAccountMismatch
+        );
+
+        Ok(())
+    }
+
+    /// Ensure the lock account supplied for an investor matches its registry entry
+    pub fn verify_lock_account(&self, investor_index: u32, stream: &Pubkey) -> Result<()> {
+        let entry = self.entry(investor_index)?;
+
+        require_keys_eq!(*stream, entry.stream, crate::error::HonouraryError::InvestorAccountMismatch);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::plan_page_payouts;
+
+    #[test]
+    fn test_removed_slot_is_skipped_with_zero_weight() {
+        let mut registry = InvestorRegistry {
+            vault: Pubkey::default(),
+            page_index: 0,
+            active_count: 2,
+            bump: 0,
+            entries: [InvestorEntry::default(); INVESTORS_PER_REGISTRY_PAGE as usize],
+        };
+        for (slot, locked) in [(0, 300), (2, 700)] {
+            registry.entries[slot] = InvestorEntry {
+                stream: Pubkey::new_unique(),
+                recipient: Pubkey::new_unique(),
+                payout_ata: Pubkey::new_unique(),
+                active: true,
+                locked_snapshot: locked,
+            };
+        }
+
+        // Slot 1 was removed: it reads as empty rather than failing the page
+        assert!(registry.entry(1).is_err());
+        assert!(registry.active_entry(1).unwrap().is_none());
+        assert!(registry.active_entry(INVESTORS_PER_REGISTRY_PAGE).is_err());
+
+        let individual_locked: Vec<u64> = (0..3)
+            .map(|index| registry.active_entry(index).unwrap().map_or(0, |entry| entry.locked_snapshot))
+            .collect();
+        let plan = plan_page_payouts(1_000, &individual_locked, 1_000, 0, 0, 0, None).unwrap();
+        assert_eq!(plan.payouts, vec![300, 0, 700]);
+    }
+}
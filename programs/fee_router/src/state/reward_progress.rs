@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+
+/// Distribution ledger for one cp-amm farm of the honorary position's pool.
+/// A reward day follows the fee distribution day it was claimed in and pays out
+/// against that day's sealed locked-amount snapshot.
+#[account]
+#[derive(InitSpace)]
+pub struct RewardProgress {
+    /// The vault this ledger belongs to
+    pub vault: Pubkey,
+
+    /// cp-amm reward index (0 or 1)
+    pub reward_index: u8,
+
+    /// PDA bump seed
+    pub bump: u8,
+
+    /// Reward token mint
+    pub reward_mint: Pubkey,
+
+    /// Distribution day (DistributionProgress::current_day) of the reward day below
+    pub day: u64,
+
+    /// Rewards distributable in this day (claimed + carried from earlier days)
+    pub day_total_claimed: u64,
+
+    /// Rewards paid to investors in this day
+    pub day_distributed: u64,
+
+    /// Dust carried between pages of this day
+    pub day_carry_over: u64,
+
+    /// Rewards carried into the next day
+    pub persistent_carry_over: u64,
+
+    /// Next investor index to pay
+    pub pagination_cursor: u32,
+
+    /// Whether this day's rewards have been claimed from the position
+    pub day_claimed: bool,
+
+    /// Whether this day's payouts are finished
+    pub day_completed: bool,
+
+    /// Total lifetime rewards claimed from the position
+    pub total_claimed: u64,
+
+    /// Total lifetime rewards distributed to investors
+    pub total_investor_distributed: u64,
+
+    /// Total lifetime rewards distributed to the creator side
+    pub total_creator_distributed: u64,
+
+    /// Rewards escrowed for investors whose reward account could not receive them
+    /// and not yet claimed (held in the reward treasury)
+    pub pending_payout: u64,
+}
+
+impl RewardProgress {
+    pub const SEEDS_PREFIX: &'static [u8] = crate::constants::REWARD_PROGRESS_SEED;
+
+    /// Move the ledger to a new distribution day. Whatever an unfinished earlier
+    /// day did not pay out is carried forward rather than lost.
+    pub fn begin_day(&mut self, day: u64) {
+        if !self.day_completed {
+            let undistributed = self.day_total_claimed.saturating_sub(self.day_distributed);
+            self.persistent_carry_over = self.persistent_carry_over.saturating_add(undistributed);
+        }
+
+        self.day = day;
+        self.day_total_claimed = 0;
+        self.day_distributed = 0;
+        self.day_carry_over = 0;
+        self.pagination_cursor = 0;
+        self.day_claimed = false;
+        self.day_completed = false;
+    }
+
+    /// Open payouts with the rewards claimed for the day
+    pub fn start_payouts(&mut self, claimed: u64) {
+        self.day_total_claimed = claimed.saturating_add(self.persistent_carry_over);
+        self.persistent_carry_over = 0;
+        self.total_claimed = self.total_claimed.saturating_add(claimed);
+        self.day_claimed = true;
+    }
+
+    /// Close the day and keep its dust for the next one
+    pub fn complete_day(&mut self, creator_amount: u64) {
+        self.day_completed = true;
+        self.total_creator_distributed = self.total_creator_distributed.saturating_add(creator_amount);
+        self.persistent_carry_over = self.day_carry_over;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unfinished_day_carries_forward() {
+        let mut progress = RewardProgress {
+            vault: Pubkey::default(),
+            reward_index: 0,
+            bump: 0,
+            reward_mint: Pubkey::default(),
+            day: 0,
+            day_total_claimed: 0,
+            day_distributed: 0,
+            day_carry_over: 0,
+            persistent_carry_over: 0,
+            pagination_cursor: 0,
+            day_claimed: false,
+            day_completed: true,
+            total_claimed: 0,
+            total_investor_distributed: 0,
+            total_creator_distributed: 0,
+            pending_payout: 0,
+        };
+
+        progress.begin_day(1);
+        progress.start_payouts(1_000);
+        progress.day_distributed = 400;
+
+        // Day 1 never reached its final page: the unpaid 600 rolls into day 2
+        progress.begin_day(2);
+        progress.start_payouts(500);
+        assert_eq!(progress.day_total_claimed, 1_100);
+
+        progress.day_carry_over = 7;
+        progress.complete_day(1_093);
+        progress.begin_day(3);
+        progress.start_payouts(0);
+        assert_eq!(progress.day_total_claimed, 7);
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
 use crate::error::HonouraryError;
+use ruint::aliases::U256;
 
 /// Calculate eligible investor share based on locked token percentage
 pub fn calculate_eligible_investor_share_bps(
@@ -40,7 +41,8 @@ pub fn calculate_investor_fee_amount(
         .map_err(|_| HonouraryError::MathOverflow.into())
 }
 
-/// Calculate individual investor payout based on their locked amount
+/// Calculate individual investor payout based on their locked amount (or their
+/// time-weighted average locked amount, per the policy's weighting mode)
 pub fn calculate_individual_payout(
     total_investor_fee: u64,
     individual_locked: u64,
@@ -59,6 +61,103 @@ pub fn calculate_individual_payout(
         .map_err(|_| HonouraryError::MathOverflow.into())
 }
 
+/// Average of a quantity over `[from, to)` given its integral over that span;
+/// an empty span has no average, so the caller falls back to a point reading
+pub fn time_weighted_average(integral: u128, from: u64, to: u64) -> Option<u64> {
+    let span = to.checked_sub(from).filter(|span| *span > 0)?;
+    u64::try_from(integral / span as u128).ok()
+}
+
+/// ∫₀ˣ ⌊u / period⌋ du: time integral of the number of whole periods elapsed
+fn whole_periods_integral(x: u64, period: u64) -> u128 {
+    let periods = (x / period) as u128;
+    let period = period as u128;
+    // Whole periods contribute 0 + 1 + ... + (periods - 1), each for `period`;
+    // the partial period at the end contributes `periods` for its length
+    ((periods * periods.saturating_sub(1)) / 2).saturating_mul(period)
+        .saturating_add(periods.saturating_mul(x as u128 - periods * period))
+}
+
+/// Unlock schedule made of a cliff release and a stream of equal releases
+/// every `period`, in the schedule's own points (timestamps or slots)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepUnlock {
+    /// Point `cliff_amount` is released at
+    pub cliff_point: u64,
+    pub cliff_amount: u64,
+    /// Point the stream releases `stream_initial` at and counts periods from
+    pub stream_start: u64,
+    pub stream_initial: u64,
+    pub period: u64,
+    pub amount_per_period: u64,
+    /// Most the stream releases (excluding the cliff)
+    pub stream_cap: u64,
+    /// Point the stream stops releasing at (`u64::MAX` when it never does)
+    pub frozen_at: u64,
+}
+
+impl StepUnlock {
+    /// Amount unlocked at `point`
+    pub fn unlocked_at(&self, point: u64) -> u64 {
+        let cliff = if point >= self.cliff_point { self.cliff_amount } else { 0 };
+        if point < self.stream_start {
+            return cliff;
+        }
+        let elapsed = point.min(self.frozen_at.max(self.stream_start)) - self.stream_start;
+        cliff.saturating_add(self.streamed_after(elapsed))
+    }
+
+    /// ∫ unlocked over `[from, to)`
+    pub fn unlocked_integral(&self, from: u64, to: u64) -> u128 {
+        self.integral_to(to).saturating_sub(self.integral_to(from))
+    }
+
+    /// Stream release after `elapsed` points of streaming
+    fn streamed_after(&self, elapsed: u64) -> u64 {
+        let periods = elapsed.checked_div(self.period).unwrap_or(0) as u128;
+        let streamed = (self.stream_initial as u128).saturating_add(
+            periods.saturating_mul(self.amount_per_period as u128)
+        );
+        streamed.min(self.stream_cap as u128) as u64
+    }
+
+    /// ∫₀ˣ unlocked
+    fn integral_to(&self, x: u64) -> u128 {
+        let cliff = (self.cliff_amount as u128).saturating_mul(x.saturating_sub(self.cliff_point) as u128);
+        if x <= self.stream_start {
+            return cliff;
+        }
+
+        let streaming_end = x.min(self.frozen_at.max(self.stream_start));
+        let streaming = self.streamed_integral(streaming_end - self.stream_start);
+        let frozen = (self.streamed_after(streaming_end - self.stream_start) as u128).saturating_mul(
+            (x - streaming_end) as u128
+        );
+
+        cliff.saturating_add(streaming).saturating_add(frozen)
+    }
+
+    /// ∫₀ᵉ of the stream release, `e` points after `stream_start`
+    fn streamed_integral(&self, elapsed: u64) -> u128 {
+        let initial = self.stream_initial.min(self.stream_cap);
+        if self.period == 0 || self.amount_per_period == 0 || initial == self.stream_cap {
+            return (initial as u128).saturating_mul(elapsed as u128);
+        }
+
+        // Points after which every period's release is capped
+        let periods_to_cap = (self.stream_cap - initial).div_ceil(self.amount_per_period);
+        let capped_after = periods_to_cap.saturating_mul(self.period);
+        let uncapped = elapsed.min(capped_after);
+
+        (initial as u128)
+            .saturating_mul(uncapped as u128)
+            .saturating_add(
+                (self.amount_per_period as u128).saturating_mul(whole_periods_integral(uncapped, self.period))
+            )
+            .saturating_add((self.stream_cap as u128).saturating_mul((elapsed - uncapped) as u128))
+    }
+}
+
 /// Apply dust threshold and minimum payout rules
 pub fn apply_dust_threshold(calculated_amount: u64, min_payout_threshold: u64) -> (u64, u64) {
     if calculated_amount >= min_payout_threshold {
@@ -103,6 +202,228 @@ pub fn check_daily_cap(
     }
 }
 
+/// Transfers planned for one page of investors in a single token
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PagePayouts {
+    /// Amount owed to each investor on the page (pro-rata share plus carry-over share)
+    pub payouts: Vec<u64>,
+    /// Sum of `payouts`
+    pub distributed: u64,
+    /// Dust carried to the next page (or day)
+    pub dust: u64,
+    /// Payouts cut by the daily cap, settled by the policy's cap overflow mode
+    pub overflow: u64,
+}
+
+/// Plan one page of pro-rata payouts for a token.
+///
+/// Each investor receives `total_investor_fee * locked / total_locked`, subject to the
+/// dust threshold and the daily cap. Carry-over from earlier pages is handed out
+/// pro-rata to this page once it reaches the dust threshold. Amounts cut by the
+/// cap are reported separately from dust and never re-enter the page carry.
+pub fn plan_page_payouts(
+    total_investor_fee: u64,
+    individual_locked: &[u64],
+    total_locked: u64,
+    carry_over: u64,
+    min_payout: u64,
+    already_distributed: u64,
+    daily_cap: Option<u64>
+) -> Result<PagePayouts> {
+    let page_locked: u64 = individual_locked.iter().sum();
+    let mut plan = PagePayouts {
+        payouts: vec![0; individual_locked.len()],
+        ..Default::default()
+    };
+
+    // Page share of carry-over from previous pages, if it is worth distributing
+    let mut carry_over_distributed = 0u64;
+    if carry_over >= min_payout && total_locked > 0 {
+        carry_over_distributed = (carry_over as u128)
+            .saturating_mul(page_locked as u128)
+            .saturating_div(total_locked as u128) as u64;
+    }
+
+    for (payout, locked_amount) in plan.payouts.iter_mut().zip(individual_locked) {
+        let individual_payout = calculate_individual_payout(
+            total_investor_fee,
+            *locked_amount,
+            total_locked
+        )?;
+        let (final_payout, dust) = apply_dust_threshold(individual_payout, min_payout);
+
+        if final_payout > 0 {
+            let allowed_payout = check_daily_cap(
+                already_distributed.saturating_add(plan.distributed),
+                final_payout,
+                daily_cap
+            )?;
+            *payout = allowed_payout;
+            plan.distributed = plan.distributed.saturating_add(allowed_payout);
+
+            // Cap-limited amounts are settled at day close
+            plan.overflow = plan.overflow.saturating_add(final_payout.saturating_sub(allowed_payout));
+        } else {
+            plan.dust = plan.dust.saturating_add(dust);
+        }
+    }
+
+    if carry_over_distributed > 0 && page_locked > 0 {
+        for (payout, locked_amount) in plan.payouts.iter_mut().zip(individual_locked) {
+            let investor_dust_share = (carry_over_distributed as u128)
+                .saturating_mul(*locked_amount as u128)
+                .saturating_div(page_locked as u128) as u64;
+
+            *payout = payout.saturating_add(investor_dust_share);
+            plan.distributed = plan.distributed.saturating_add(investor_dust_share);
+        }
+
+        // Rounding remainder of the distributed carry-over stays with the creator,
+        // the undistributed part keeps carrying
+        plan.dust = plan.dust.saturating_add(carry_over.saturating_sub(carry_over_distributed));
+    } else {
+        plan.dust = plan.dust.saturating_add(carry_over);
+    }
+
+    Ok(plan)
+}
+
+/// Multiply two Q64.64-scaled values and shift the product back down by 64 bits
+fn mul_shr_64(a: u128, b: u128) -> Option<u128> {
+    let (a_hi, a_lo) = (a >> 64, a & u64::MAX as u128);
+    let (b_hi, b_lo) = (b >> 64, b & u64::MAX as u128);
+
+    (a_hi.checked_mul(b_hi)?.checked_shl(64)?)
+        .checked_add(a_hi.checked_mul(b_lo)?)?
+        .checked_add(a_lo.checked_mul(b_hi)?)?
+        .checked_add(a_lo.checked_mul(b_lo)? >> 64)
+}
+
+/// Output of swapping `amount_in` at the pool's Q64.64 `sqrt_price` (B per A), before fees
+pub fn amount_out_at_sqrt_price(amount_in: u64, sqrt_price: u128, a_to_b: bool) -> Result<u64> {
+    require!(sqrt_price > 0, HonouraryError::InvalidPoolConfiguration);
+
+    // B->A uses the inverse price: 2^128 / sqrt_price is (approximately) 1 / sqrt_price in Q64.64
+    let factor = if a_to_b { sqrt_price } else { u128::MAX / sqrt_price };
+
+    let out = mul_shr_64(amount_in as u128, factor)
+        .and_then(|partial| mul_shr_64(partial, factor))
+        .ok_or(HonouraryError::MathOverflow)?;
+
+    Ok(std::cmp::min(out, u64::MAX as u128) as u64)
+}
+
+/// Minimum acceptable output after allowing `max_slippage_bps` below `expected_out`
+pub fn apply_slippage(expected_out: u64, max_slippage_bps: u16) -> Result<u64> {
+    let keep_bps = (BASIS_POINTS_DIVISOR as u16).saturating_sub(max_slippage_bps);
+    calculate_investor_fee_amount(expected_out, keep_bps)
+}
+
+/// Token amounts (rounded up, as cp-amm charges them) needed to add `liquidity`
+/// at the pool's current price; mirrors cp-amm's `get_amounts_for_modify_liquidity`
+pub fn amounts_for_liquidity(
+    liquidity: u128,
+    sqrt_price: u128,
+    sqrt_min_price: u128,
+    sqrt_max_price: u128
+) -> Result<(u64, u64)> {
+    require!(
+        sqrt_min_price <= sqrt_price && sqrt_price <= sqrt_max_price && sqrt_price > 0,
+        HonouraryError::InvalidPoolConfiguration
+    );
+
+    // Δa = L * (√P_max - √P) / (√P * √P_max)
+    let amount_a = (U256::from(liquidity) * U256::from(sqrt_max_price - sqrt_price)).div_ceil(
+        U256::from(sqrt_price) * U256::from(sqrt_max_price)
+    );
+    // Δb = L * (√P - √P_min) / 2^128
+    let amount_b = (U256::from(liquidity) * U256::from(sqrt_price - sqrt_min_price)).div_ceil(
+        U256::from(1u8) << 128
+    );
+
+    Ok((to_u64(amount_a)?, to_u64(amount_b)?))
+}
+
+/// Largest liquidity delta whose `amounts_for_liquidity` fits within both amounts
+pub fn max_liquidity_for_amounts(
+    amount_a: u64,
+    amount_b: u64,
+    sqrt_price: u128,
+    sqrt_min_price: u128,
+    sqrt_max_price: u128
+) -> Result<u128> {
+    require!(
+        sqrt_min_price <= sqrt_price && sqrt_price <= sqrt_max_price && sqrt_price > 0,
+        HonouraryError::InvalidPoolConfiguration
+    );
+
+    // Solve against one unit less than available so cp-amm's round-up still fits
+    let liquidity_from_a = if sqrt_price == sqrt_max_price {
+        U256::MAX
+    } else {
+        (U256::from(amount_a.saturating_sub(1)) * U256::from(sqrt_price) * U256::from(sqrt_max_price)) /
+            U256::from(sqrt_max_price - sqrt_price)
+    };
+    let liquidity_from_b = if sqrt_price == sqrt_min_price {
+        U256::MAX
+    } else {
+        (U256::from(amount_b.saturating_sub(1)) << 128) / U256::from(sqrt_price - sqrt_min_price)
+    };
+
+    let liquidity = std::cmp::min(liquidity_from_a, liquidity_from_b);
+    Ok(u128::try_from(liquidity).unwrap_or(u128::MAX))
+}
+
+/// Portion of a quote-only `budget` to swap into base so that both sides are in the
+/// ratio the position's price range needs when adding liquidity at the current price
+pub fn quote_to_swap_for_liquidity(
+    budget: u64,
+    sqrt_price: u128,
+    sqrt_min_price: u128,
+    sqrt_max_price: u128,
+    quote_is_token_a: bool
+) -> Result<u64> {
+    require!(
+        sqrt_min_price <= sqrt_price && sqrt_price <= sqrt_max_price && sqrt_price > 0,
+        HonouraryError::InvalidPoolConfiguration
+    );
+
+    // Value of each side per unit of liquidity, in token B: a-side √P(√P_max - √P)/√P_max,
+    // b-side √P - √P_min (common factors cancel)
+    let value_a = (U256::from(sqrt_price) * U256::from(sqrt_max_price - sqrt_price)) /
+        U256::from(sqrt_max_price);
+    let value_b = U256::from(sqrt_price - sqrt_min_price);
+    let total = value_a + value_b;
+    if total.is_zero() {
+        return Ok(0);
+    }
+
+    let base_value = if quote_is_token_a { value_b } else { value_a };
+    to_u64((U256::from(budget) * base_value) / total)
+}
+
+fn to_u64(value: U256) -> Result<u64> {
+    u64::try_from(value).map_err(|_| HonouraryError::MathOverflow.into())
+}
+
+/// Split `amount` by basis-point weights; the last share absorbs rounding dust
+pub fn split_by_bps(amount: u64, weights_bps: &[u16]) -> Result<Vec<u64>> {
+    let mut shares = Vec::with_capacity(weights_bps.len());
+    let mut allocated = 0u64;
+
+    for (i, bps) in weights_bps.iter().enumerate() {
+        let share = if i + 1 == weights_bps.len() {
+            amount.saturating_sub(allocated)
+        } else {
+            calculate_investor_fee_amount(amount, *bps)?
+        };
+        allocated = allocated.checked_add(share).ok_or(HonouraryError::MathOverflow)?;
+        shares.push(share);
+    }
+
+    Ok(shares)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,4 +449,115 @@ mod tests {
         assert_eq!(payout, 1500);
         assert_eq!(dust, 0);
     }
+
+    #[test]
+    fn test_split_by_bps() {
+        // 50/30/20 split of an amount that doesn't divide evenly
+        let shares = split_by_bps(1001, &[5000, 3000, 2000]).unwrap();
+        assert_eq!(shares, vec![500, 300, 201]);
+        assert_eq!(shares.iter().sum::<u64>(), 1001);
+    }
+
+    #[test]
+    fn test_plan_page_payouts_applies_dust_cap_and_carry() {
+        // 10_000 fee over 4 equal investors, 2 on this page
+        let plan = plan_page_payouts(10_000, &[100, 100], 400, 0, 1_000, 0, None).unwrap();
+        assert_eq!(plan.payouts, vec![2_500, 2_500]);
+        assert_eq!(plan.dust, 0);
+
+        // Daily cap of 3_000 cuts the second payout; the cut is overflow, not dust
+        let plan = plan_page_payouts(10_000, &[100, 100], 400, 0, 1_000, 0, Some(3_000)).unwrap();
+        assert_eq!(plan.payouts, vec![2_500, 500]);
+        assert_eq!(plan.dust, 0);
+        assert_eq!(plan.overflow, 2_000);
+
+        // 2_000 carried from earlier pages: this page holds half the lock, so gets 1_000
+        let plan = plan_page_payouts(0, &[100, 100], 400, 2_000, 1_000, 0, None).unwrap();
+        assert_eq!(plan.payouts, vec![500, 500]);
+        assert_eq!(plan.distributed, 1_000);
+        assert_eq!(plan.dust, 1_000);
+    }
+
+    #[test]
+    fn test_step_unlock_integral_matches_summed_points() {
+        let curves = [
+            // Cliff then a stream that hits its cap mid-period
+            StepUnlock {
+                cliff_point: 10,
+                cliff_amount: 50,
+                stream_start: 10,
+                stream_initial: 0,
+                period: 7,
+                amount_per_period: 30,
+                stream_cap: 200,
+                frozen_at: u64::MAX,
+            },
+            // Stream with an initial release, frozen by a pause
+            StepUnlock {
+                cliff_point: u64::MAX,
+                cliff_amount: 0,
+                stream_start: 25,
+                stream_initial: 40,
+                period: 3,
+                amount_per_period: 11,
+                stream_cap: 1_000,
+                frozen_at: 61,
+            },
+            // Cliff only
+            StepUnlock {
+                cliff_point: 30,
+                cliff_amount: 500,
+                stream_start: 30,
+                stream_initial: 0,
+                period: 0,
+                amount_per_period: 0,
+                stream_cap: 0,
+                frozen_at: u64::MAX,
+            },
+        ];
+
+        // Unlocks only change on whole points, so the integral is a sum of points
+        for curve in curves {
+            for (from, to) in [(0, 100), (5, 12), (10, 11), (24, 80), (60, 62), (90, 90)] {
+                let summed: u128 = (from..to).map(|point| curve.unlocked_at(point) as u128).sum();
+                assert_eq!(curve.unlocked_integral(from, to), summed, "{:?} over [{}, {})", curve, from, to);
+            }
+        }
+
+        assert_eq!(time_weighted_average(1_000, 10, 20), Some(100));
+        assert_eq!(time_weighted_average(1_000, 10, 10), None);
+    }
+
+    #[test]
+    fn test_amount_out_at_sqrt_price() {
+        // sqrt_price of 2.0 in Q64.64 => price of 4 B per A
+        let sqrt_two = 2u128 << 64;
+        assert_eq!(amount_out_at_sqrt_price(1_000, sqrt_two, true).unwrap(), 4_000);
+        // Inverse direction rounds down slightly
+        let out = amount_out_at_sqrt_price(4_000, sqrt_two, false).unwrap();
+        assert!(out == 999 || out == 1_000);
+
+        // 1% slippage on the expected output
+        assert_eq!(apply_slippage(4_000, 100).unwrap(), 3_960);
+    }
+
+    #[test]
+    fn test_liquidity_for_amounts_fits_within_budget() {
+        // Price 1.0 in a [0.25, 4] range
+        let (sqrt_min, sqrt_price, sqrt_max) = (1u128 << 63, 1u128 << 64, 2u128 << 64);
+
+        let liquidity = max_liquidity_for_amounts(1_000_000, 1_000_000, sqrt_price, sqrt_min, sqrt_max)
+            .unwrap();
+        let (amount_a, amount_b) = amounts_for_liquidity(liquidity, sqrt_price, sqrt_min, sqrt_max)
+            .unwrap();
+        assert!(amount_a <= 1_000_000 && amount_b <= 1_000_000);
+        // Symmetric range around the price: both sides are (almost) fully used
+        assert!(amount_a >= 999_990 && amount_b >= 999_990);
+
+        // Quote-only budget: the symmetric range needs half of its value on each side
+        assert_eq!(quote_to_swap_for_liquidity(1_000_000, sqrt_price, sqrt_min, sqrt_max, false).unwrap(), 500_000);
+
+        // Nothing on one side means no liquidity can be added
+        assert_eq!(max_liquidity_for_amounts(0, 1_000_000, sqrt_price, sqrt_min, sqrt_max).unwrap(), 0);
+    }
 }
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+// Domain separation so a leaf can never be passed off as an inner node
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Hash a distribution leaf: (leaf index, investor wallet, quote amount)
+pub fn distribution_leaf(index: u32, investor: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, &index.to_le_bytes(), investor.as_ref(), &amount.to_le_bytes()]).to_bytes()
+}
+
+/// Hash two sibling nodes (sorted, so proofs don't need direction flags)
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Verify a Merkle proof for `leaf` against `root`
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling));
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_proof_for_four_leaves() {
+        let investors: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = investors
+            .iter()
+            .enumerate()
+            .map(|(i, investor)| distribution_leaf(i as u32, investor, 1_000 * (i as u64 + 1)))
+            .collect();
+
+        let left = hash_pair(&leaves[0], &leaves[1]);
+        let right = hash_pair(&leaves[2], &leaves[3]);
+        let root = hash_pair(&left, &right);
+
+        assert!(verify_proof(&[leaves[3], left], &root, leaves[2]));
+
+        // Wrong amount, wrong index or a truncated proof must all fail
+        assert!(!verify_proof(&[leaves[3], left], &root, distribution_leaf(2, &investors[2], 1)));
+        assert!(!verify_proof(&[leaves[3], left], &root, distribution_leaf(3, &investors[2], 3_000)));
+        assert!(!verify_proof(&[leaves[3]], &root, leaves[2]));
+    }
+}
@@ -1,7 +1,11 @@
 pub mod pda;
 pub mod validation;
 pub mod math;
+pub mod merkle;
+pub mod token;
 
 pub use pda::*;
 pub use validation::*;
-pub use math::*;
\ No newline at end of file
+pub use math::*;
+pub use merkle::*;
+pub use token::*;
\ No newline at end of file
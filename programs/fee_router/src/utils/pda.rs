@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{ self, Allocate, Assign, Transfer };
 use crate::constants::*;
 
 /// Derive the investor fee position owner PDA
@@ -71,4 +72,41 @@ pub fn position_owner_signer_seeds<'a>(
         INVESTOR_FEE_POS_OWNER_SEED,
         bump,
     ]
-}
\ No newline at end of file
+}
+
+/// Create a per-investor PDA owned by this program. Allocating in place (rather
+/// than create_account) keeps a pre-funded PDA address from blocking the page.
+pub fn create_investor_pda<'info>(
+    info: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>
+) -> Result<()> {
+    let rent_due = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer { from: payer.clone(), to: info.clone() }
+            ),
+            rent_due
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate { account_to_allocate: info.clone() },
+            &[signer_seeds]
+        ),
+        space as u64
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign { account_to_assign: info.clone() },
+            &[signer_seeds]
+        ),
+        &crate::ID
+    )
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::{ TransferFeeConfig, MAX_FEE_BASIS_POINTS },
+            BaseStateWithExtensions,
+            StateWithExtensions,
+        },
+    },
+    token_interface::{ Mint, TokenAccount },
+};
+use crate::error::HonouraryError;
+
+/// Amount to send so that `amount` arrives after the mint's current Token-2022
+/// transfer fee (same inverse-fee rule cp-amm uses for its deposits)
+pub fn transfer_fee_included_amount(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let mint_info = mint.to_account_info();
+    if amount == 0 || *mint_info.owner == Token::id() {
+        return Ok(amount);
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let Ok(transfer_fee_config) = mint_state.get_extension::<TransferFeeConfig>() else {
+        return Ok(amount);
+    };
+
+    let epoch_fee = transfer_fee_config.get_epoch_fee(Clock::get()?.epoch);
+    let fee = if u16::from(epoch_fee.transfer_fee_basis_points) == MAX_FEE_BASIS_POINTS {
+        // A 100% fee has no inverse; the fee is always the maximum
+        u64::from(epoch_fee.maximum_fee)
+    } else {
+        epoch_fee.calculate_inverse_fee(amount).ok_or(HonouraryError::MathOverflow)?
+    };
+
+    amount.checked_add(fee).ok_or(HonouraryError::MathOverflow.into())
+}
+
+/// Whether a payout account can receive `mint` for `owner` now. Closed, frozen and
+/// non-token accounts cannot, and neither can a token account of another mint or
+/// owner; their payouts are escrowed for the investor instead.
+pub fn payout_account_open<'info>(
+    account: &'info AccountInfo<'info>,
+    mint: Pubkey,
+    owner: Pubkey
+) -> bool {
+    let Ok(token_account) = InterfaceAccount::<TokenAccount>::try_from(account) else {
+        return false;
+    };
+
+    token_account.mint == mint && token_account.owner == owner && !token_account.is_frozen()
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::cp_amm_types::Pool;
 use crate::error::HonouraryError;
+use crate::state::FeeCollection;
 
 /// Validate that a pool is configured for quote-only fee collection
 pub fn validate_quote_only_pool(
@@ -70,13 +71,39 @@ pub fn validate_position_ownership(
     Ok(())
 }
 
+/// Validate a pool for both-token fee collection (BothToken or OnlyB pools)
+pub fn validate_both_token_pool(
+    pool: &Pool,
+    expected_quote_mint: &Pubkey,
+) -> Result<()> {
+    require!(
+        pool.collect_fee_mode == 0 || pool.collect_fee_mode == 1,
+        HonouraryError::InvalidPoolConfiguration
+    );
+    require!(
+        pool.token_a_mint == *expected_quote_mint || pool.token_b_mint == *expected_quote_mint,
+        HonouraryError::InvalidPoolConfiguration
+    );
+    require!(
+        pool.pool_status == 0,
+        HonouraryError::InvalidPoolConfiguration
+    );
+
+    Ok(())
+}
+
 /// Pre-flight validation before creating position
 pub fn preflight_position_validation(
     pool: &Pool,
     quote_mint: &Pubkey,
+    fee_collection: FeeCollection,
 ) -> Result<()> {
-    // Comprehensive validation to prevent any base token fee accrual
-    validate_quote_only_pool(pool, quote_mint)?;
+    match fee_collection {
+        // Comprehensive validation to prevent any base token fee accrual
+        FeeCollection::QuoteOnly => validate_quote_only_pool(pool, quote_mint)?,
+        // Base fees are expected and distributed alongside quote fees
+        FeeCollection::BothTokens => validate_both_token_pool(pool, quote_mint)?,
+    }
     
     // Check pool tick configuration if applicable
     // (Add specific tick/price validations based on DAMM v2 requirements)